@@ -2,14 +2,279 @@ use chrono::Utc;
 use sysinfo::{CpuRefreshKind, Disks, Networks, System};
 use std::time::Duration;
 use std::process::Command;
+use std::net::{TcpStream, ToSocketAddrs, UdpSocket};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
 use crate::types::{
-    CpuMetrics, DiskMetrics, LoadAverage, MemoryMetrics, MemoryModule, NetworkInterface, NetworkMetrics,
-    OsInfo, SystemMetrics, PingMetrics, PingTarget, PingTargetConfig,
+    CpuMetrics, DiskMetrics, DnsMetrics, DnsResolverResult, LoadAverage, MemoryMetrics, MemoryModule,
+    NetworkErrorStats, NetworkInterface, NetworkLimits, NetworkMetrics, OsInfo, SystemMetrics, PingMetrics,
+    PingTarget, PingTargetConfig, ProbeKind, ProtocolTotals, TcpMemLimits,
 };
 
+/// Cumulative interface-level error/drop counters summed from `/proc/net/dev`,
+/// used to compute the per-second rates in `NetworkErrorStats`.
+#[derive(Debug, Clone, Copy, Default)]
+struct NetDevRawTotals {
+    rx_errors: u64,
+    rx_dropped: u64,
+    rx_fifo: u64,
+    tx_errors: u64,
+    tx_dropped: u64,
+    tx_fifo: u64,
+    tx_collisions: u64,
+}
+
+/// Cumulative UDP/TCP counters parsed from the `Udp:`/`Tcp:` rows of
+/// `/proc/net/snmp`, with the IPv6 `Udp6*` counters from `/proc/net/snmp6`
+/// folded into the same UDP fields, used to compute the per-second rates in
+/// `NetworkErrorStats`.
+#[derive(Debug, Clone, Copy, Default)]
+struct SnmpRawTotals {
+    udp_in_datagrams: u64,
+    udp_out_datagrams: u64,
+    udp_in_errors: u64,
+    udp_rcvbuf_errors: u64,
+    udp_sndbuf_errors: u64,
+    udp_no_ports: u64,
+    udp_in_csum_errors: u64,
+    tcp_retrans_segs: u64,
+    tcp_in_errors: u64,
+}
+
+/// Per-disk I/O counter baseline stored between collection cycles, so
+/// `apply_disk_io_stats` can turn cumulative counters into per-second rates.
+/// On Linux: (reads completed, sectors read, ms reading, writes completed,
+/// sectors written, ms writing, sampled-at), from `/proc/diskstats`. On
+/// macOS: (read ops, read bytes, read ns, write ops, write bytes, write ns,
+/// sampled-at), from each disk's IOKit `Statistics` dict. Unused on Windows,
+/// where `Win32_PerfFormattedData_PerfDisk_PhysicalDisk` already reports
+/// rates directly.
+type DiskIoTotals = (u64, u64, u64, u64, u64, u64, std::time::Instant);
+
+/// A minimal parsed value from Apple's XML property-list format, just deep
+/// enough to walk `diskutil ... -plist` output (dicts/arrays/strings/
+/// integers/bools) without pulling in a plist crate.
+#[cfg(target_os = "macos")]
+#[derive(Debug, Clone)]
+enum PlistValue {
+    Dict(Vec<(String, PlistValue)>),
+    Array(Vec<PlistValue>),
+    String(String),
+    Integer(i64),
+    Bool(bool),
+}
+
+#[cfg(target_os = "macos")]
+impl PlistValue {
+    fn as_array(&self) -> Option<&[PlistValue]> {
+        match self {
+            PlistValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            PlistValue::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            PlistValue::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            PlistValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&PlistValue> {
+        match self {
+            PlistValue::Dict(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+/// One XML tag/text event from a hand-rolled plist tokenizer. Good enough
+/// for `diskutil`'s well-formed, attribute-free plist output; not a general
+/// XML parser.
+#[cfg(target_os = "macos")]
+#[derive(Clone, Copy)]
+enum PlistToken<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    SelfClose(&'a str),
+    Text(&'a str),
+}
+
+#[cfg(target_os = "macos")]
+fn tokenize_plist(xml: &str) -> Vec<PlistToken<'_>> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < xml.len() {
+        if xml.as_bytes()[pos] == b'<' {
+            let end = xml[pos..].find('>').map(|e| pos + e).unwrap_or(xml.len());
+            let tag = xml[pos + 1..end].trim();
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(PlistToken::Close(name.trim()));
+            } else if let Some(name) = tag.strip_suffix('/') {
+                tokens.push(PlistToken::SelfClose(name.trim()));
+            } else if !tag.starts_with('?') && !tag.starts_with('!') {
+                let name = tag.split_whitespace().next().unwrap_or(tag);
+                tokens.push(PlistToken::Open(name));
+            }
+            pos = end + 1;
+        } else {
+            let next_lt = xml[pos..].find('<').map(|e| pos + e).unwrap_or(xml.len());
+            let text = xml[pos..next_lt].trim();
+            if !text.is_empty() {
+                tokens.push(PlistToken::Text(text));
+            }
+            pos = next_lt;
+        }
+    }
+
+    tokens
+}
+
+#[cfg(target_os = "macos")]
+fn parse_plist_value(tokens: &[PlistToken], idx: &mut usize) -> Option<PlistValue> {
+    while *idx < tokens.len() {
+        match tokens[*idx] {
+            PlistToken::Open("dict") => {
+                *idx += 1;
+                let mut entries = Vec::new();
+                loop {
+                    match tokens.get(*idx) {
+                        Some(PlistToken::Close("dict")) => {
+                            *idx += 1;
+                            break;
+                        }
+                        Some(PlistToken::Open("key")) => {
+                            *idx += 1;
+                            let key = match tokens.get(*idx) {
+                                Some(PlistToken::Text(t)) => {
+                                    *idx += 1;
+                                    t.to_string()
+                                }
+                                _ => String::new(),
+                            };
+                            if matches!(tokens.get(*idx), Some(PlistToken::Close("key"))) {
+                                *idx += 1;
+                            }
+                            let value = parse_plist_value(tokens, idx)?;
+                            entries.push((key, value));
+                        }
+                        None => break,
+                        _ => {
+                            *idx += 1;
+                        }
+                    }
+                }
+                return Some(PlistValue::Dict(entries));
+            }
+            PlistToken::Open("array") => {
+                *idx += 1;
+                let mut items = Vec::new();
+                loop {
+                    match tokens.get(*idx) {
+                        Some(PlistToken::Close("array")) => {
+                            *idx += 1;
+                            break;
+                        }
+                        None => break,
+                        _ => match parse_plist_value(tokens, idx) {
+                            Some(value) => items.push(value),
+                            None => break,
+                        },
+                    }
+                }
+                return Some(PlistValue::Array(items));
+            }
+            PlistToken::Open("string") => {
+                *idx += 1;
+                let text = match tokens.get(*idx) {
+                    Some(PlistToken::Text(t)) => {
+                        let s = t.to_string();
+                        *idx += 1;
+                        s
+                    }
+                    _ => String::new(),
+                };
+                if matches!(tokens.get(*idx), Some(PlistToken::Close("string"))) {
+                    *idx += 1;
+                }
+                return Some(PlistValue::String(text));
+            }
+            PlistToken::Open("integer") => {
+                *idx += 1;
+                let n = match tokens.get(*idx) {
+                    Some(PlistToken::Text(t)) => {
+                        let n = t.parse::<i64>().unwrap_or(0);
+                        *idx += 1;
+                        n
+                    }
+                    _ => 0,
+                };
+                if matches!(tokens.get(*idx), Some(PlistToken::Close("integer"))) {
+                    *idx += 1;
+                }
+                return Some(PlistValue::Integer(n));
+            }
+            PlistToken::SelfClose("true") => {
+                *idx += 1;
+                return Some(PlistValue::Bool(true));
+            }
+            PlistToken::SelfClose("false") => {
+                *idx += 1;
+                return Some(PlistValue::Bool(false));
+            }
+            _ => {
+                *idx += 1;
+            }
+        }
+    }
+    None
+}
+
+/// Parse a `plist -x`/`diskutil ... -plist` XML document down to its root
+/// `<dict>`.
+#[cfg(target_os = "macos")]
+fn parse_plist(xml: &str) -> Option<PlistValue> {
+    let tokens = tokenize_plist(xml);
+    let mut idx = 0;
+    // The document root is `<plist version="1.0"><dict>...</dict></plist>`;
+    // skip straight to the first real container.
+    while idx < tokens.len() {
+        match tokens[idx] {
+            PlistToken::Open("dict") | PlistToken::Open("array") => {
+                return parse_plist_value(&tokens, &mut idx);
+            }
+            _ => idx += 1,
+        }
+    }
+    None
+}
+
+/// Per-second rate between two monotonic counter readings; 0 if the counter
+/// went backwards (reset/overflow) instead of panicking on underflow.
+fn counter_rate(current: u64, previous: u64, elapsed_secs: f64) -> f64 {
+    if current >= previous {
+        (current - previous) as f64 / elapsed_secs
+    } else {
+        0.0
+    }
+}
+
 /// Default ping targets for latency monitoring
 const DEFAULT_PING_TARGETS: &[(&str, &str)] = &[
     ("Google DNS", "8.8.8.8"),
@@ -17,21 +282,58 @@ const DEFAULT_PING_TARGETS: &[(&str, &str)] = &[
     ("Local Gateway", ""),  // Will be detected
 ];
 
-/// Metrics collector that maintains state for accurate CPU measurements
+/// Hostname resolved against each discovered DNS server to measure
+/// resolution latency.
+const DNS_QUERY_HOST: &str = "example.com";
+const DNS_QUERY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Per-metric-class sample cadence for `MetricsCollector`'s background
+/// refresh threads. CPU/memory numbers are cheap to read and are sampled
+/// fastest; disk/network IO require parsing `/proc` files and run at a
+/// medium interval; DMI memory-module info and gateway detection shell out
+/// to external tools (`dmidecode` and friends) and barely change
+/// minute-to-minute, so they're sampled rarely.
+#[derive(Debug, Clone, Copy)]
+pub struct SampleIntervals {
+    pub cpu_memory: Duration,
+    pub disk_network: Duration,
+    pub static_info: Duration,
+    pub ping: Duration,
+    pub network_limits: Duration,
+}
+
+impl Default for SampleIntervals {
+    fn default() -> Self {
+        Self {
+            cpu_memory: Duration::from_secs(1),
+            disk_network: Duration::from_secs(5),
+            static_info: Duration::from_secs(300),
+            ping: Duration::from_secs(10),
+            network_limits: Duration::from_secs(3600),
+        }
+    }
+}
+
+/// Metrics collector that maintains state for accurate CPU measurements.
+///
+/// Each metric class is refreshed on its own background thread at its own
+/// cadence (see `SampleIntervals`) into an `Arc<Mutex<...>>` cache; `collect`
+/// just snapshots whatever each thread last wrote, so callers never block on
+/// a slow probe.
 pub struct MetricsCollector {
-    sys: System,
-    disks: Disks,
-    networks: Networks,
     hostname: String,
     os_info: OsInfo,
-    // Track previous network readings for speed calculation
-    last_network_rx: u64,
-    last_network_tx: u64,
-    last_network_time: std::time::Instant,
+    cpu_cache: Arc<Mutex<CpuMetrics>>,
+    memory_cache: Arc<Mutex<MemoryMetrics>>,
+    disk_cache: Arc<Mutex<Vec<DiskMetrics>>>,
+    network_cache: Arc<Mutex<NetworkMetrics>>,
     // Ping metrics (updated in background)
     ping_results: Arc<Mutex<Option<PingMetrics>>>,
-    #[allow(dead_code)] // Used during initialization for background thread
-    gateway_ip: Option<String>,
+    // DNS resolver latency metrics (updated in background, alongside ping)
+    dns_results: Arc<Mutex<Option<DnsMetrics>>>,
+    // Kernel network-buffer tunables, refreshed hourly alongside the other
+    // slow-changing static info (memory modules, gateway)
+    network_limits_cache: Arc<Mutex<Option<NetworkLimits>>>,
     // Cached IP addresses
     ip_addresses: Vec<String>,
     // Custom ping targets from server config
@@ -40,73 +342,204 @@ pub struct MetricsCollector {
 
 impl MetricsCollector {
     pub fn new() -> Self {
-        let mut sys = System::new_all();
-        
-        // Initial CPU refresh to get baseline
-        sys.refresh_cpu_specifics(CpuRefreshKind::everything());
-        std::thread::sleep(Duration::from_millis(200));
-        sys.refresh_cpu_specifics(CpuRefreshKind::everything());
-        
+        Self::with_intervals(SampleIntervals::default())
+    }
+
+    pub fn with_intervals(intervals: SampleIntervals) -> Self {
         let hostname = System::host_name().unwrap_or_else(|| "unknown".to_string());
-        
+
         let os_info = OsInfo {
             name: System::name().unwrap_or_else(|| "Unknown".to_string()),
             version: System::os_version().unwrap_or_else(|| "Unknown".to_string()),
             kernel: System::kernel_version().unwrap_or_else(|| "Unknown".to_string()),
             arch: std::env::consts::ARCH.to_string(),
         };
-        
-        let networks = Networks::new_with_refreshed_list();
-        
-        // Get initial network totals
-        let (init_rx, init_tx) = networks.iter().fold((0u64, 0u64), |(rx, tx), (_, data)| {
-            (rx.saturating_add(data.total_received()), tx.saturating_add(data.total_transmitted()))
-        });
-        
-        // Detect default gateway
-        let gateway_ip = Self::detect_gateway();
-        
+
         // Initialize ping results
         let ping_results = Arc::new(Mutex::new(None));
-        
+        let dns_results = Arc::new(Mutex::new(None));
+
         // Initialize custom ping targets
         let custom_ping_targets: Arc<Mutex<Option<Vec<PingTargetConfig>>>> = Arc::new(Mutex::new(None));
-        
-        // Start background ping thread
+
+        // Static info (memory modules, default gateway) refreshed rarely on
+        // its own thread; seed it synchronously so the first `collect()`
+        // isn't missing data while the thread's first tick is still pending.
+        let memory_modules_cache = Arc::new(Mutex::new(Self::collect_memory_modules()));
+        let gateway_cache = Arc::new(Mutex::new(Self::detect_gateway()));
+        // Kernel network-buffer tunables change even less often than the
+        // rest of this thread's slice, so they're gated behind their own,
+        // longer-lived `Instant` baseline instead of refreshing every tick.
+        let network_limits_cache = Arc::new(Mutex::new(Self::read_network_limits()));
+
+        let memory_modules_clone = Arc::clone(&memory_modules_cache);
+        let gateway_clone = Arc::clone(&gateway_cache);
+        let network_limits_clone = Arc::clone(&network_limits_cache);
+        let static_interval = intervals.static_info;
+        let network_limits_interval = intervals.network_limits;
+        thread::spawn(move || {
+            let mut last_limits_refresh = std::time::Instant::now();
+            loop {
+                thread::sleep(static_interval);
+                if let Ok(mut guard) = memory_modules_clone.lock() {
+                    *guard = Self::collect_memory_modules();
+                }
+                if let Ok(mut guard) = gateway_clone.lock() {
+                    *guard = Self::detect_gateway();
+                }
+
+                if last_limits_refresh.elapsed() >= network_limits_interval {
+                    if let Ok(mut guard) = network_limits_clone.lock() {
+                        *guard = Self::read_network_limits();
+                    }
+                    last_limits_refresh = std::time::Instant::now();
+                }
+            }
+        });
+
+        // CPU/memory numbers refreshed fast, merging in whatever the static
+        // thread last found for memory modules.
+        let mut sys = System::new_all();
+        sys.refresh_cpu_specifics(CpuRefreshKind::everything());
+        std::thread::sleep(Duration::from_millis(200));
+        sys.refresh_cpu_specifics(CpuRefreshKind::everything());
+        sys.refresh_memory();
+
+        let initial_modules = memory_modules_cache.lock().ok().map(|g| g.clone()).unwrap_or_default();
+        let cpu_cache = Arc::new(Mutex::new(Self::sample_cpu(&sys)));
+        let memory_cache = Arc::new(Mutex::new(Self::sample_memory(&sys, initial_modules)));
+
+        let cpu_cache_clone = Arc::clone(&cpu_cache);
+        let memory_cache_clone = Arc::clone(&memory_cache);
+        let memory_modules_clone = Arc::clone(&memory_modules_cache);
+        let cpu_memory_interval = intervals.cpu_memory;
+        thread::spawn(move || loop {
+            thread::sleep(cpu_memory_interval);
+            sys.refresh_cpu_specifics(CpuRefreshKind::everything());
+            sys.refresh_memory();
+
+            if let Ok(mut guard) = cpu_cache_clone.lock() {
+                *guard = Self::sample_cpu(&sys);
+            }
+            let modules = memory_modules_clone.lock().ok().map(|g| g.clone()).unwrap_or_default();
+            if let Ok(mut guard) = memory_cache_clone.lock() {
+                *guard = Self::sample_memory(&sys, modules);
+            }
+        });
+
+        // Disk/network IO refreshed at a medium cadence; this thread owns
+        // the raw counter baselines instead of storing them on the
+        // collector, since it's the only thread that touches them.
+        let disks = Disks::new_with_refreshed_list();
+        let networks = Networks::new_with_refreshed_list();
+        let (init_rx, init_tx) = networks.iter().fold((0u64, 0u64), |(rx, tx), (_, data)| {
+            (rx.saturating_add(data.total_received()), tx.saturating_add(data.total_transmitted()))
+        });
+
+        let mut disk_io_totals = std::collections::HashMap::new();
+        let disk_cache = Arc::new(Mutex::new(Self::sample_disks(&disks, &mut disk_io_totals)));
+
+        let mut last_network_rx = init_rx;
+        let mut last_network_tx = init_tx;
+        let mut last_network_time = std::time::Instant::now();
+        let mut last_net_dev_totals: Option<NetDevRawTotals> = None;
+        let mut last_snmp_totals: Option<SnmpRawTotals> = None;
+        let mut last_interface_totals = std::collections::HashMap::new();
+        let mut last_interface_error_totals = std::collections::HashMap::new();
+        let limits = network_limits_cache.lock().ok().and_then(|g| g.clone());
+        let initial_network = Self::sample_network(
+            &networks,
+            &mut last_network_rx,
+            &mut last_network_tx,
+            &mut last_network_time,
+            &mut last_net_dev_totals,
+            &mut last_snmp_totals,
+            &mut last_interface_totals,
+            &mut last_interface_error_totals,
+            limits,
+        );
+        let network_cache = Arc::new(Mutex::new(initial_network));
+
+        let disk_cache_clone = Arc::clone(&disk_cache);
+        let network_cache_clone = Arc::clone(&network_cache);
+        let network_limits_for_net = Arc::clone(&network_limits_cache);
+        let disk_network_interval = intervals.disk_network;
+        thread::spawn(move || {
+            let mut disks = disks;
+            let mut networks = networks;
+
+            loop {
+                thread::sleep(disk_network_interval);
+                disks.refresh();
+                networks.refresh();
+
+                if let Ok(mut guard) = disk_cache_clone.lock() {
+                    *guard = Self::sample_disks(&disks, &mut disk_io_totals);
+                }
+
+                let limits = network_limits_for_net.lock().ok().and_then(|g| g.clone());
+                let network = Self::sample_network(
+                    &networks,
+                    &mut last_network_rx,
+                    &mut last_network_tx,
+                    &mut last_network_time,
+                    &mut last_net_dev_totals,
+                    &mut last_snmp_totals,
+                    &mut last_interface_totals,
+                    &mut last_interface_error_totals,
+                    limits,
+                );
+                if let Ok(mut guard) = network_cache_clone.lock() {
+                    *guard = network;
+                }
+            }
+        });
+
+        // Start background ping thread (also times DNS resolution against
+        // each discovered resolver, since both are latency-to-a-remote-host
+        // checks on the same cadence)
         let ping_results_clone = Arc::clone(&ping_results);
+        let dns_results_clone = Arc::clone(&dns_results);
         let custom_targets_clone = Arc::clone(&custom_ping_targets);
-        let gateway_clone = gateway_ip.clone();
+        let gateway_for_ping = Arc::clone(&gateway_cache);
+        let ping_interval = intervals.ping;
         thread::spawn(move || {
             loop {
                 // Check for custom targets from server config
                 let custom_targets = custom_targets_clone.lock().ok().and_then(|guard| guard.clone());
-                let results = Self::collect_ping_with_targets(&gateway_clone, custom_targets.as_ref());
+                let gateway_ip = gateway_for_ping.lock().ok().and_then(|guard| guard.clone());
+                let results = Self::collect_ping_with_targets(&gateway_ip, custom_targets.as_ref());
                 if let Ok(mut guard) = ping_results_clone.lock() {
                     *guard = Some(results);
                 }
-                thread::sleep(Duration::from_secs(10)); // Ping every 10 seconds
+
+                let dns = Self::collect_dns_metrics();
+                if let Ok(mut guard) = dns_results_clone.lock() {
+                    *guard = Some(dns);
+                }
+
+                thread::sleep(ping_interval);
             }
         });
-        
+
         // Collect IP addresses
         let ip_addresses = Self::collect_ip_addresses();
-        
+
         Self {
-            sys,
-            disks: Disks::new_with_refreshed_list(),
-            networks,
             hostname,
             os_info,
-            last_network_rx: init_rx,
-            last_network_tx: init_tx,
-            last_network_time: std::time::Instant::now(),
+            cpu_cache,
+            memory_cache,
+            disk_cache,
+            network_cache,
             ping_results,
-            gateway_ip,
+            dns_results,
+            network_limits_cache,
             ip_addresses,
             custom_ping_targets,
         }
     }
-    
+
     /// Update ping targets from server configuration
     pub fn set_ping_targets(&self, targets: Vec<PingTargetConfig>) {
         if let Ok(mut guard) = self.custom_ping_targets.lock() {
@@ -344,7 +777,63 @@ impl MetricsCollector {
             Err(_) => (None, 100.0, "error".to_string()),
         }
     }
-    
+
+    /// TCP connect-timeout probe against `host:port`, run fully in-process
+    /// (no `Command`, no elevated privileges). Connects three times,
+    /// measuring each attempt's wall-clock duration with `Instant::now()`;
+    /// the average of the successful connects is reported as `latency_ms`,
+    /// and refused/timed-out attempts count toward `packet_loss`.
+    fn probe_tcp(host: &str, port: u16) -> (Option<f64>, f64, String) {
+        let addr = match (host, port)
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+        {
+            Some(addr) => addr,
+            None => return (None, 100.0, "error".to_string()),
+        };
+
+        const ATTEMPTS: u32 = 3;
+        let mut successes = 0;
+        let mut refused = 0;
+        let mut total_ms = 0.0;
+
+        for _ in 0..ATTEMPTS {
+            let start = std::time::Instant::now();
+            match TcpStream::connect_timeout(&addr, Duration::from_secs(2)) {
+                Ok(_) => {
+                    total_ms += start.elapsed().as_secs_f64() * 1000.0;
+                    successes += 1;
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => {
+                    refused += 1;
+                }
+                Err(_) => {}
+            }
+        }
+
+        let packet_loss = ((ATTEMPTS - successes) as f64 / ATTEMPTS as f64) * 100.0;
+        let latency_ms = if successes > 0 {
+            Some(total_ms / successes as f64)
+        } else {
+            None
+        };
+        let status = if successes == ATTEMPTS {
+            "ok".to_string()
+        } else if refused == ATTEMPTS {
+            "refused".to_string()
+        } else if successes > 0 {
+            // Some attempts connected and some didn't: flag this distinctly
+            // from "ok" so a flapping target's `packet_loss` isn't masked by
+            // a status that implies full health.
+            "degraded".to_string()
+        } else {
+            "timeout".to_string()
+        };
+
+        (latency_ms, packet_loss, status)
+    }
+
     /// Collect ping metrics with custom targets from server config
     fn collect_ping_with_targets(gateway_ip: &Option<String>, custom_targets: Option<&Vec<PingTargetConfig>>) -> PingMetrics {
         let mut targets = Vec::new();
@@ -355,13 +844,21 @@ impl MetricsCollector {
                 if target.host.is_empty() {
                     continue;
                 }
-                let (latency, packet_loss, status) = Self::ping_host(&target.host);
+                let (latency, packet_loss, status) = match target.probe {
+                    ProbeKind::Tcp => match target.port {
+                        Some(port) => Self::probe_tcp(&target.host, port),
+                        None => (None, 100.0, "error".to_string()),
+                    },
+                    ProbeKind::Icmp => Self::ping_host(&target.host),
+                };
                 targets.push(PingTarget {
                     name: target.name.clone(),
                     host: target.host.clone(),
                     latency_ms: latency,
                     packet_loss,
                     status,
+                    port: target.port,
+                    probe: target.probe,
                 });
             }
         } else {
@@ -385,51 +882,226 @@ impl MetricsCollector {
                     latency_ms: latency,
                     packet_loss,
                     status,
+                    port: None,
+                    probe: ProbeKind::Icmp,
                 });
             }
         }
         
         PingMetrics { targets }
     }
-    
-    /// Refresh and collect current system metrics
-    pub fn collect(&mut self) -> SystemMetrics {
-        // Refresh all metrics
-        self.sys.refresh_cpu_specifics(CpuRefreshKind::everything());
-        self.sys.refresh_memory();
-        self.disks.refresh();
-        self.networks.refresh();
-        
-        let network = self.collect_network();
-        
-        // Get cached ping results
+
+    /// Discover the host's configured DNS resolvers and time a real lookup
+    /// of `DNS_QUERY_HOST` against each one, so DNS latency/failures show up
+    /// as a distinct failure domain from the ICMP/TCP ping targets.
+    fn collect_dns_metrics() -> DnsMetrics {
+        let resolvers = Self::discover_dns_resolvers()
+            .into_iter()
+            .map(|address| {
+                let (resolve_ms, status) = Self::resolve_via(&address, DNS_QUERY_HOST, DNS_QUERY_TIMEOUT);
+                DnsResolverResult {
+                    address,
+                    resolve_ms,
+                    status,
+                }
+            })
+            .collect();
+
+        DnsMetrics { resolvers }
+    }
+
+    /// Parse `/etc/resolv.conf` `nameserver` lines, supplemented with
+    /// `resolvectl status` when available (useful when `/etc/resolv.conf`
+    /// just points at systemd-resolved's local stub listener).
+    #[cfg(target_os = "linux")]
+    fn discover_dns_resolvers() -> Vec<String> {
+        let mut servers = Vec::new();
+
+        if let Ok(content) = std::fs::read_to_string("/etc/resolv.conf") {
+            for line in content.lines() {
+                if let Some(rest) = line.trim().strip_prefix("nameserver") {
+                    if let Some(addr) = rest.split_whitespace().next() {
+                        servers.push(addr.to_string());
+                    }
+                }
+            }
+        }
+
+        if let Ok(output) = Command::new("resolvectl").arg("status").output() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            for line in stdout.lines() {
+                let line = line.trim();
+                let rest = line
+                    .strip_prefix("DNS Servers:")
+                    .or_else(|| line.strip_prefix("Current DNS Server:"));
+                if let Some(rest) = rest {
+                    for addr in rest.split_whitespace() {
+                        if !servers.iter().any(|s| s == addr) {
+                            servers.push(addr.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        servers
+    }
+
+    /// Parse `scutil --dns`'s `nameserver[N] : <addr>` lines.
+    #[cfg(target_os = "macos")]
+    fn discover_dns_resolvers() -> Vec<String> {
+        let mut servers = Vec::new();
+
+        if let Ok(output) = Command::new("scutil").arg("--dns").output() {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            for line in stdout.lines() {
+                let line = line.trim();
+                if let Some((key, value)) = line.split_once(':') {
+                    if key.trim().starts_with("nameserver[") {
+                        let addr = value.trim();
+                        if !addr.is_empty() && !servers.iter().any(|s| s == addr) {
+                            servers.push(addr.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        servers
+    }
+
+    #[cfg(target_os = "windows")]
+    fn discover_dns_resolvers() -> Vec<String> {
+        let mut servers = Vec::new();
+
+        if let Ok(output) = Command::new("powershell")
+            .args([
+                "-NoProfile",
+                "-Command",
+                "(Get-DnsClientServerAddress -AddressFamily IPv4).ServerAddresses",
+            ])
+            .output()
+        {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            for line in stdout.lines() {
+                let addr = line.trim();
+                if !addr.is_empty() && !servers.iter().any(|s| s == addr) {
+                    servers.push(addr.to_string());
+                }
+            }
+        }
+
+        servers
+    }
+
+    /// Time a real DNS lookup of `host` against `server`, hand-rolling a
+    /// minimal UDP DNS query (a single A-record question) instead of
+    /// shelling out, mirroring how `probe_tcp` measures service latency
+    /// fully in-process. Only the header's RCODE is inspected — we care
+    /// about round-trip time and success/failure, not the answer itself.
+    fn resolve_via(server: &str, host: &str, timeout: Duration) -> (Option<f64>, String) {
+        let addr = match (server, 53u16)
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+        {
+            Some(addr) => addr,
+            None => return (None, "error".to_string()),
+        };
+
+        let socket = match UdpSocket::bind(("0.0.0.0", 0)) {
+            Ok(socket) => socket,
+            Err(_) => return (None, "error".to_string()),
+        };
+        if socket.set_read_timeout(Some(timeout)).is_err() {
+            return (None, "error".to_string());
+        }
+
+        let query = Self::build_dns_query(host);
+        let start = std::time::Instant::now();
+        if socket.send_to(&query, addr).is_err() {
+            return (None, "error".to_string());
+        }
+
+        let mut buf = [0u8; 512];
+        match socket.recv(&mut buf) {
+            Ok(len) if len >= 12 => {
+                let resolve_ms = start.elapsed().as_secs_f64() * 1000.0;
+                let rcode = buf[3] & 0x0F;
+                let status = if rcode == 0 { "ok".to_string() } else { "error".to_string() };
+                (Some(resolve_ms), status)
+            }
+            Ok(_) => (None, "error".to_string()),
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock || e.kind() == std::io::ErrorKind::TimedOut => {
+                (None, "timeout".to_string())
+            }
+            Err(_) => (None, "error".to_string()),
+        }
+    }
+
+    /// Build a minimal DNS query packet: standard query, recursion desired,
+    /// one question for `host`'s A record.
+    fn build_dns_query(host: &str) -> Vec<u8> {
+        let mut packet = Vec::with_capacity(32);
+        packet.extend_from_slice(&[0x12, 0x34]); // transaction ID
+        packet.extend_from_slice(&[0x01, 0x00]); // flags: RD=1
+        packet.extend_from_slice(&[0x00, 0x01]); // QDCOUNT=1
+        packet.extend_from_slice(&[0x00, 0x00]); // ANCOUNT=0
+        packet.extend_from_slice(&[0x00, 0x00]); // NSCOUNT=0
+        packet.extend_from_slice(&[0x00, 0x00]); // ARCOUNT=0
+
+        for label in host.split('.') {
+            packet.push(label.len() as u8);
+            packet.extend_from_slice(label.as_bytes());
+        }
+        packet.push(0x00); // root label
+
+        packet.extend_from_slice(&[0x00, 0x01]); // QTYPE=A
+        packet.extend_from_slice(&[0x00, 0x01]); // QCLASS=IN
+
+        packet
+    }
+
+    /// Snapshot the latest cached values from each background sampling
+    /// thread. Never blocks on a slow probe (disk/network parsing, a
+    /// `dmidecode` shellout, a ping round-trip) — it just reads whatever
+    /// that thread last wrote.
+    pub fn collect(&self) -> SystemMetrics {
+        let cpu = self.cpu_cache.lock().ok().map(|c| c.clone()).unwrap_or_default();
+        let memory = self.memory_cache.lock().ok().map(|m| m.clone()).unwrap_or_default();
+        let disks = self.disk_cache.lock().ok().map(|d| d.clone()).unwrap_or_default();
+        let network = self.network_cache.lock().ok().map(|n| n.clone()).unwrap_or_default();
+
+        // Get cached ping/DNS results
         let ping = self.ping_results.lock().ok().and_then(|guard| guard.clone());
-        
+        let dns = self.dns_results.lock().ok().and_then(|guard| guard.clone());
+
         SystemMetrics {
             timestamp: Utc::now(),
             hostname: self.hostname.clone(),
             os: self.os_info.clone(),
-            cpu: self.collect_cpu(),
-            memory: self.collect_memory(),
-            disks: self.collect_disks(),
+            cpu,
+            memory,
+            disks,
             network,
             uptime: System::uptime(),
             load_average: self.collect_load_average(),
             ping,
+            dns,
             version: Some(env!("CARGO_PKG_VERSION").to_string()),
             ip_addresses: if self.ip_addresses.is_empty() { None } else { Some(self.ip_addresses.clone()) },
         }
     }
-    
-    fn collect_cpu(&self) -> CpuMetrics {
-        let cpus = self.sys.cpus();
+
+    fn sample_cpu(sys: &System) -> CpuMetrics {
+        let cpus = sys.cpus();
         let global_usage: f32 = cpus.iter().map(|c| c.cpu_usage()).sum::<f32>() / cpus.len() as f32;
         let per_core: Vec<f32> = cpus.iter().map(|c| c.cpu_usage()).collect();
         let frequency = cpus.first().map(|c| c.frequency()).unwrap_or(0);
         let brand = cpus.first()
             .map(|c| c.brand().to_string())
             .unwrap_or_else(|| "Unknown".to_string());
-        
+
         CpuMetrics {
             brand,
             cores: cpus.len(),
@@ -438,23 +1110,23 @@ impl MetricsCollector {
             per_core,
         }
     }
-    
-    fn collect_memory(&self) -> MemoryMetrics {
-        let total = self.sys.total_memory();
-        let used = self.sys.used_memory();
-        let available = self.sys.available_memory();
-        let swap_total = self.sys.total_swap();
-        let swap_used = self.sys.used_swap();
-        
+
+    /// Numeric memory stats, refreshed at `SampleIntervals::cpu_memory`
+    /// cadence; `modules` is filled in from the separately (and much more
+    /// rarely) refreshed `memory_modules_cache`.
+    fn sample_memory(sys: &System, modules: Vec<MemoryModule>) -> MemoryMetrics {
+        let total = sys.total_memory();
+        let used = sys.used_memory();
+        let available = sys.available_memory();
+        let swap_total = sys.total_swap();
+        let swap_used = sys.used_swap();
+
         let usage_percent = if total > 0 {
             (used as f32 / total as f32) * 100.0
         } else {
             0.0
         };
-        
-        // Collect memory module details
-        let modules = Self::collect_memory_modules();
-        
+
         MemoryMetrics {
             total,
             used,
@@ -627,11 +1299,284 @@ impl MetricsCollector {
         modules
     }
     
-    fn collect_disks(&self) -> Vec<DiskMetrics> {
+    fn sample_disks(
+        disks: &Disks,
+        disk_io_totals: &mut std::collections::HashMap<String, DiskIoTotals>,
+    ) -> Vec<DiskMetrics> {
         // Collect physical disks instead of partitions
-        Self::collect_physical_disks(&self.disks)
+        let mut result = Self::collect_physical_disks(disks);
+        Self::apply_disk_io_stats(&mut result, disk_io_totals);
+        result
     }
-    
+
+    /// Fill in `read_bytes_per_sec`/`write_bytes_per_sec`/`read_iops`/`write_iops`
+    /// and average `read_latency_ms`/`write_latency_ms` from `/proc/net/dev`'s
+    /// sibling, `/proc/diskstats`, using the same stored-baseline-plus-elapsed-time
+    /// approach as `sample_network`. Latency is approximated as
+    /// `Δms_<op> / Δ<op>s_completed` over the interval. Skips the first sample
+    /// for a device (no prior baseline) by leaving the fields at their zero/None
+    /// defaults.
+    #[cfg(target_os = "linux")]
+    fn apply_disk_io_stats(
+        disks: &mut [DiskMetrics],
+        disk_io_totals: &mut std::collections::HashMap<String, DiskIoTotals>,
+    ) {
+        let now = std::time::Instant::now();
+        let Some(raw) = Self::read_proc_diskstats() else {
+            return;
+        };
+
+        for disk in disks.iter_mut() {
+            let Some(&(reads, read_sectors, ms_reading, writes, write_sectors, ms_writing)) =
+                raw.get(&disk.name)
+            else {
+                continue;
+            };
+
+            if let Some(&(
+                prev_reads,
+                prev_read_sectors,
+                prev_ms_reading,
+                prev_writes,
+                prev_write_sectors,
+                prev_ms_writing,
+                prev_time,
+            )) = disk_io_totals.get(&disk.name)
+            {
+                let elapsed_secs = now.duration_since(prev_time).as_secs_f64();
+                if elapsed_secs > 0.1 {
+                    disk.read_iops = counter_rate(reads, prev_reads, elapsed_secs) as u64;
+                    disk.write_iops = counter_rate(writes, prev_writes, elapsed_secs) as u64;
+                    disk.read_bytes_per_sec =
+                        (counter_rate(read_sectors, prev_read_sectors, elapsed_secs) * 512.0) as u64;
+                    disk.write_bytes_per_sec =
+                        (counter_rate(write_sectors, prev_write_sectors, elapsed_secs) * 512.0) as u64;
+
+                    let delta_reads = reads.saturating_sub(prev_reads);
+                    if delta_reads > 0 {
+                        let delta_ms_reading = ms_reading.saturating_sub(prev_ms_reading);
+                        disk.read_latency_ms = Some(delta_ms_reading as f64 / delta_reads as f64);
+                    }
+                    let delta_writes = writes.saturating_sub(prev_writes);
+                    if delta_writes > 0 {
+                        let delta_ms_writing = ms_writing.saturating_sub(prev_ms_writing);
+                        disk.write_latency_ms = Some(delta_ms_writing as f64 / delta_writes as f64);
+                    }
+                }
+            }
+
+            disk_io_totals.insert(
+                disk.name.clone(),
+                (reads, read_sectors, ms_reading, writes, write_sectors, ms_writing, now),
+            );
+        }
+    }
+
+    /// Same fields as the Linux version above, sourced from the
+    /// `Statistics` dict each `IOBlockStorageDriver` keeps (cumulative
+    /// operations/bytes/total-time-in-nanoseconds since boot), using the
+    /// same stored-baseline-plus-elapsed-time approach as `sample_network`.
+    #[cfg(target_os = "macos")]
+    fn apply_disk_io_stats(
+        disks: &mut [DiskMetrics],
+        disk_io_totals: &mut std::collections::HashMap<String, DiskIoTotals>,
+    ) {
+        let now = std::time::Instant::now();
+        let Some(raw) = Self::read_ioreg_disk_stats() else {
+            return;
+        };
+
+        for disk in disks.iter_mut() {
+            let Some(&(read_ops, read_bytes, read_ns, write_ops, write_bytes, write_ns)) =
+                raw.get(&disk.name)
+            else {
+                continue;
+            };
+
+            if let Some(&(
+                prev_read_ops,
+                prev_read_bytes,
+                prev_read_ns,
+                prev_write_ops,
+                prev_write_bytes,
+                prev_write_ns,
+                prev_time,
+            )) = disk_io_totals.get(&disk.name)
+            {
+                let elapsed_secs = now.duration_since(prev_time).as_secs_f64();
+                if elapsed_secs > 0.1 {
+                    disk.read_iops = counter_rate(read_ops, prev_read_ops, elapsed_secs) as u64;
+                    disk.write_iops = counter_rate(write_ops, prev_write_ops, elapsed_secs) as u64;
+                    disk.read_bytes_per_sec =
+                        counter_rate(read_bytes, prev_read_bytes, elapsed_secs) as u64;
+                    disk.write_bytes_per_sec =
+                        counter_rate(write_bytes, prev_write_bytes, elapsed_secs) as u64;
+
+                    let delta_read_ops = read_ops.saturating_sub(prev_read_ops);
+                    if delta_read_ops > 0 {
+                        let delta_read_ns = read_ns.saturating_sub(prev_read_ns);
+                        disk.read_latency_ms =
+                            Some(delta_read_ns as f64 / delta_read_ops as f64 / 1_000_000.0);
+                    }
+                    let delta_write_ops = write_ops.saturating_sub(prev_write_ops);
+                    if delta_write_ops > 0 {
+                        let delta_write_ns = write_ns.saturating_sub(prev_write_ns);
+                        disk.write_latency_ms =
+                            Some(delta_write_ns as f64 / delta_write_ops as f64 / 1_000_000.0);
+                    }
+                }
+            }
+
+            disk_io_totals.insert(
+                disk.name.clone(),
+                (read_ops, read_bytes, read_ns, write_ops, write_bytes, write_ns, now),
+            );
+        }
+    }
+
+    /// `Win32_PerfFormattedData_PerfDisk_PhysicalDisk` already reports
+    /// pre-computed per-second rates and average seconds-per-operation, so
+    /// unlike the Linux/macOS versions above there's no stored baseline to
+    /// maintain here.
+    #[cfg(target_os = "windows")]
+    fn apply_disk_io_stats(
+        disks: &mut [DiskMetrics],
+        _disk_io_totals: &mut std::collections::HashMap<String, DiskIoTotals>,
+    ) {
+        let Ok(output) = Command::new("wmic")
+            .args([
+                "path",
+                "Win32_PerfFormattedData_PerfDisk_PhysicalDisk",
+                "get",
+                "AvgDiskSecPerRead,AvgDiskSecPerWrite,DiskReadBytesPersec,DiskReadsPersec,DiskWriteBytesPersec,DiskWritesPersec,Name",
+                "/format:csv",
+            ])
+            .output()
+        else {
+            return;
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for line in stdout.lines().skip(1) {
+            let parts: Vec<&str> = line.split(',').collect();
+            if parts.len() < 8 {
+                continue;
+            }
+
+            // Instance names look like "0 C:" (physical disk index, then the
+            // drive letters it carries); map the index back onto the
+            // "PHYSICALDRIVEn" name `collect_physical_disks` assigned from
+            // WMIC's DeviceID.
+            let Some(index) = parts[7].trim().split_whitespace().next() else {
+                continue;
+            };
+            let name = format!("PHYSICALDRIVE{index}");
+            let Some(disk) = disks.iter_mut().find(|d| d.name == name) else {
+                continue;
+            };
+
+            disk.read_bytes_per_sec = parts[3].trim().parse::<u64>().unwrap_or(0);
+            disk.read_iops = parts[4].trim().parse::<u64>().unwrap_or(0);
+            disk.write_bytes_per_sec = parts[5].trim().parse::<u64>().unwrap_or(0);
+            disk.write_iops = parts[6].trim().parse::<u64>().unwrap_or(0);
+            disk.read_latency_ms = parts[1].trim().parse::<f64>().ok().map(|secs| secs * 1000.0);
+            disk.write_latency_ms = parts[2].trim().parse::<f64>().ok().map(|secs| secs * 1000.0);
+        }
+    }
+
+    /// Parse `/proc/diskstats` into device name -> (reads completed, sectors
+    /// read, ms spent reading, writes completed, sectors written, ms spent
+    /// writing), keyed by the field layout documented in the kernel's
+    /// `Documentation/admin-guide/iostats.rst`: field 3 = device name, field 4
+    /// = reads completed, field 6 = sectors read, field 7 = ms reading, field
+    /// 8 = writes completed, field 10 = sectors written, field 11 = ms writing.
+    #[cfg(target_os = "linux")]
+    fn read_proc_diskstats() -> Option<std::collections::HashMap<String, (u64, u64, u64, u64, u64, u64)>> {
+        let content = std::fs::read_to_string("/proc/diskstats").ok()?;
+        let mut totals = std::collections::HashMap::new();
+
+        for line in content.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 11 {
+                continue;
+            }
+
+            let name = fields[2];
+            if name.starts_with("loop") || name.starts_with("ram") || name.starts_with("dm-")
+                || name.starts_with("sr") || name.starts_with("fd")
+            {
+                continue;
+            }
+
+            let reads = fields[3].parse::<u64>().unwrap_or(0);
+            let read_sectors = fields[5].parse::<u64>().unwrap_or(0);
+            let ms_reading = fields[6].parse::<u64>().unwrap_or(0);
+            let writes = fields[7].parse::<u64>().unwrap_or(0);
+            let write_sectors = fields[9].parse::<u64>().unwrap_or(0);
+            let ms_writing = fields[10].parse::<u64>().unwrap_or(0);
+
+            totals.insert(
+                name.to_string(),
+                (reads, read_sectors, ms_reading, writes, write_sectors, ms_writing),
+            );
+        }
+
+        Some(totals)
+    }
+
+    /// Walk `ioreg -c IOBlockStorageDriver -r -a`'s plist output for each
+    /// driver's `Statistics` dict (operations/bytes/total time in
+    /// nanoseconds, all cumulative since boot) and the whole-disk `BSD Name`
+    /// of the `IOMedia` child it drives, keyed the same way
+    /// `collect_physical_disks` names macOS disks (e.g. "disk0"). Returns
+    /// device name -> (read ops, read bytes, read ns, write ops, write
+    /// bytes, write ns).
+    #[cfg(target_os = "macos")]
+    fn read_ioreg_disk_stats() -> Option<std::collections::HashMap<String, (u64, u64, u64, u64, u64, u64)>> {
+        let output = Command::new("ioreg")
+            .args(["-c", "IOBlockStorageDriver", "-r", "-a"])
+            .output()
+            .ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let root = parse_plist(&stdout)?;
+        let entries = root.as_array()?;
+
+        let mut totals = std::collections::HashMap::new();
+        for entry in entries {
+            let Some(stats) = entry.get("Statistics") else {
+                continue;
+            };
+            let Some(name) = Self::find_bsd_whole_disk_name(entry) else {
+                continue;
+            };
+
+            let read_ops = stats.get("Operations (Read)").and_then(|v| v.as_i64()).unwrap_or(0) as u64;
+            let read_bytes = stats.get("Bytes (Read)").and_then(|v| v.as_i64()).unwrap_or(0) as u64;
+            let read_ns = stats.get("Total Time (Read)").and_then(|v| v.as_i64()).unwrap_or(0) as u64;
+            let write_ops = stats.get("Operations (Write)").and_then(|v| v.as_i64()).unwrap_or(0) as u64;
+            let write_bytes = stats.get("Bytes (Write)").and_then(|v| v.as_i64()).unwrap_or(0) as u64;
+            let write_ns = stats.get("Total Time (Write)").and_then(|v| v.as_i64()).unwrap_or(0) as u64;
+
+            totals.insert(name, (read_ops, read_bytes, read_ns, write_ops, write_bytes, write_ns));
+        }
+
+        Some(totals)
+    }
+
+    /// Find the whole-disk `BSD Name` (e.g. "disk0", not a partition like
+    /// "disk0s1") among an `IOBlockStorageDriver` entry's
+    /// `IORegistryEntryChildren`.
+    #[cfg(target_os = "macos")]
+    fn find_bsd_whole_disk_name(entry: &PlistValue) -> Option<String> {
+        let children = entry.get("IORegistryEntryChildren")?.as_array()?;
+        children.iter().find_map(|child| {
+            if child.get("Whole").and_then(|v| v.as_bool()) != Some(true) {
+                return None;
+            }
+            child.get("BSD Name").and_then(|v| v.as_str()).map(|s| s.to_string())
+        })
+    }
+
     /// Collect physical disk information
     fn collect_physical_disks(partitions: &Disks) -> Vec<DiskMetrics> {
         let mut physical_disks: std::collections::HashMap<String, DiskMetrics> = std::collections::HashMap::new();
@@ -688,6 +1633,12 @@ impl MetricsCollector {
                         mount_points: Vec::new(),
                         usage_percent: 0.0,
                         used: 0,
+                        read_bytes_per_sec: 0,
+                        write_bytes_per_sec: 0,
+                        read_iops: 0,
+                        write_iops: 0,
+                        read_latency_ms: None,
+                        write_latency_ms: None,
                     });
                 }
             }
@@ -776,6 +1727,12 @@ impl MetricsCollector {
                                 mount_points: Vec::new(),
                                 usage_percent: 0.0,
                                 used: 0,
+                                read_bytes_per_sec: 0,
+                                write_bytes_per_sec: 0,
+                                read_iops: 0,
+                                write_iops: 0,
+                                read_latency_ms: None,
+                                write_latency_ms: None,
                             });
                         }
                     }
@@ -803,6 +1760,12 @@ impl MetricsCollector {
                             mount_points: vec![mount],
                             usage_percent: usage,
                             used,
+                            read_bytes_per_sec: 0,
+                            write_bytes_per_sec: 0,
+                            read_iops: 0,
+                            write_iops: 0,
+                            read_latency_ms: None,
+                            write_latency_ms: None,
                         });
                     }
                 }
@@ -811,50 +1774,180 @@ impl MetricsCollector {
         
         #[cfg(target_os = "macos")]
         {
-            // Use diskutil to get physical disks
-            if let Ok(output) = Command::new("diskutil")
-                .args(["list", "-plist"])
-                .output()
-            {
-                // Parse plist output - simplified approach
+            if let Ok(output) = Command::new("diskutil").args(["list", "-plist"]).output() {
                 let stdout = String::from_utf8_lossy(&output.stdout);
-                // For macOS, fall back to partition-based reporting
-                for partition in partitions.iter() {
-                    let name = partition.name().to_string_lossy().to_string();
-                    let mount = partition.mount_point().to_string_lossy().to_string();
-                    
-                    // Skip system volumes
-                    if mount.starts_with("/System") || name.contains("synthesized") {
-                        continue;
+                if let Some(root) = parse_plist(&stdout) {
+                    let all = root
+                        .get("AllDisksAndPartitions")
+                        .and_then(|v| v.as_array())
+                        .unwrap_or(&[]);
+
+                    // Map every partition/APFS-volume device id to the whole
+                    // disk it's listed under, so a container's physical store
+                    // (itself just a partition, e.g. "disk0s2") can be traced
+                    // back to the real physical disk ("disk0").
+                    let mut parent_of: std::collections::HashMap<String, String> =
+                        std::collections::HashMap::new();
+                    for disk in all {
+                        let Some(device) = disk.get("DeviceIdentifier").and_then(|v| v.as_str()) else {
+                            continue;
+                        };
+                        for key in ["Partitions", "APFSVolumes"] {
+                            let Some(children) = disk.get(key).and_then(|v| v.as_array()) else {
+                                continue;
+                            };
+                            for child in children {
+                                if let Some(child_id) = child.get("DeviceIdentifier").and_then(|v| v.as_str()) {
+                                    parent_of.insert(child_id.to_string(), device.to_string());
+                                }
+                            }
+                        }
                     }
-                    
-                    let total = partition.total_space();
-                    let available = partition.available_space();
-                    let used = total.saturating_sub(available);
-                    let usage = if total > 0 { (used as f32 / total as f32) * 100.0 } else { 0.0 };
-                    
-                    if total > 0 && (mount == "/" || !mount.is_empty()) {
-                        let disk_name = name.trim_start_matches("/dev/").to_string();
-                        if !physical_disks.contains_key(&disk_name) {
-                            physical_disks.insert(disk_name.clone(), DiskMetrics {
-                                name: disk_name,
-                                model: None,
-                                serial: None,
-                                total,
-                                disk_type: Some("SSD".to_string()), // Most Macs use SSD
-                                mount_points: vec![mount],
-                                usage_percent: usage,
-                                used,
-                            });
+
+                    // Whole disks first: entries without an "APFSPhysicalStores"
+                    // key are real physical disks rather than synthesized APFS
+                    // containers, so insert those and enrich them via
+                    // `diskutil info`.
+                    for disk in all {
+                        if disk.get("APFSPhysicalStores").is_some() {
+                            continue;
+                        }
+                        let Some(device) = disk.get("DeviceIdentifier").and_then(|v| v.as_str()) else {
+                            continue;
+                        };
+                        let total = disk.get("Size").and_then(|v| v.as_i64()).unwrap_or(0) as u64;
+                        if total == 0 {
+                            continue;
+                        }
+
+                        let (model, serial, disk_type) = Self::enrich_macos_disk(device);
+                        let mut mount_points = Vec::new();
+                        if let Some(parts) = disk.get("Partitions").and_then(|v| v.as_array()) {
+                            for part in parts {
+                                if let Some(mount) = part.get("MountPoint").and_then(|v| v.as_str()) {
+                                    if !mount.is_empty() {
+                                        mount_points.push(mount.to_string());
+                                    }
+                                }
+                            }
+                        }
+
+                        physical_disks.insert(device.to_string(), DiskMetrics {
+                            name: device.to_string(),
+                            model,
+                            serial,
+                            total,
+                            disk_type,
+                            mount_points,
+                            usage_percent: 0.0,
+                            used: 0,
+                            read_bytes_per_sec: 0,
+                            write_bytes_per_sec: 0,
+                            read_iops: 0,
+                            write_iops: 0,
+                            read_latency_ms: None,
+                            write_latency_ms: None,
+                        });
+                    }
+
+                    // Synthesized APFS containers: fold their volumes' mount
+                    // points into the physical disk backing them instead of
+                    // double counting the container as its own disk.
+                    for disk in all {
+                        let Some(stores) = disk.get("APFSPhysicalStores").and_then(|v| v.as_array()) else {
+                            continue;
+                        };
+                        let Some(volumes) = disk.get("APFSVolumes").and_then(|v| v.as_array()) else {
+                            continue;
+                        };
+
+                        for store in stores {
+                            let Some(store_id) = store.get("DeviceIdentifier").and_then(|v| v.as_str()) else {
+                                continue;
+                            };
+                            let Some(physical_id) = parent_of.get(store_id) else {
+                                continue;
+                            };
+                            let Some(phys) = physical_disks.get_mut(physical_id) else {
+                                continue;
+                            };
+
+                            for volume in volumes {
+                                if let Some(mount) = volume.get("MountPoint").and_then(|v| v.as_str()) {
+                                    if !mount.is_empty() && !phys.mount_points.iter().any(|m| m == mount) {
+                                        phys.mount_points.push(mount.to_string());
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Attribute real used space from the already-mounted
+                    // filesystems, the same way the Linux/Windows branches do,
+                    // rather than trusting the plist's static partition sizes.
+                    for partition in partitions.iter() {
+                        let mount_point = partition.mount_point().to_string_lossy().to_string();
+                        if mount_point.is_empty() {
+                            continue;
+                        }
+                        for disk in physical_disks.values_mut() {
+                            if disk.mount_points.iter().any(|m| m == &mount_point) {
+                                let total = partition.total_space();
+                                let available = partition.available_space();
+                                disk.used = disk.used.saturating_add(total.saturating_sub(available));
+                            }
+                        }
+                    }
+
+                    for disk in physical_disks.values_mut() {
+                        if disk.total > 0 {
+                            disk.usage_percent = (disk.used as f32 / disk.total as f32) * 100.0;
                         }
                     }
                 }
-                let _ = stdout; // Suppress unused warning
             }
         }
-        
+
         physical_disks.into_values().collect()
     }
+
+    /// Enrich a macOS whole disk with `diskutil info -plist <device>`:
+    /// `MediaName` for the model, `SerialNumber` (falling back to
+    /// `IORegistryEntryName` when the drive doesn't report one, as is common
+    /// for internal Apple SSDs) for the serial, and `SolidState`/`Protocol`
+    /// to classify NVMe vs. SATA SSD vs. spinning disk.
+    #[cfg(target_os = "macos")]
+    fn enrich_macos_disk(device: &str) -> (Option<String>, Option<String>, Option<String>) {
+        let Ok(output) = Command::new("diskutil").args(["info", "-plist", device]).output() else {
+            return (None, None, None);
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let Some(info) = parse_plist(&stdout) else {
+            return (None, None, None);
+        };
+
+        let model = info.get("MediaName").and_then(|v| v.as_str()).map(|s| s.to_string());
+        let serial = info
+            .get("SerialNumber")
+            .or_else(|| info.get("IORegistryEntryName"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        let disk_type = match info.get("SolidState").and_then(|v| v.as_bool()) {
+            Some(true) => {
+                let protocol = info.get("Protocol").and_then(|v| v.as_str()).unwrap_or("");
+                if protocol.contains("PCI-Express") || protocol.contains("Apple Fabric") {
+                    Some("NVMe".to_string())
+                } else {
+                    Some("SSD".to_string())
+                }
+            }
+            Some(false) => Some("HDD".to_string()),
+            None => None,
+        };
+
+        (model, serial, disk_type)
+    }
     
     /// Detect disk type (SSD, HDD, NVMe)
     #[allow(dead_code)]
@@ -910,12 +2003,23 @@ impl MetricsCollector {
         None
     }
     
-    fn collect_network(&mut self) -> NetworkMetrics {
+    fn sample_network(
+        networks: &Networks,
+        last_network_rx: &mut u64,
+        last_network_tx: &mut u64,
+        last_network_time: &mut std::time::Instant,
+        last_net_dev_totals: &mut Option<NetDevRawTotals>,
+        last_snmp_totals: &mut Option<SnmpRawTotals>,
+        last_interface_totals: &mut std::collections::HashMap<String, (u64, u64, std::time::Instant)>,
+        last_interface_error_totals: &mut std::collections::HashMap<String, (u64, u64, u64, u64, std::time::Instant)>,
+        limits: Option<NetworkLimits>,
+    ) -> NetworkMetrics {
         let mut total_rx: u64 = 0;
         let mut total_tx: u64 = 0;
-        
+        let now = std::time::Instant::now();
+
         // Filter to only include physical network interfaces
-        let interfaces: Vec<NetworkInterface> = self.networks
+        let interfaces: Vec<NetworkInterface> = networks
             .iter()
             .filter(|(name, _)| Self::is_physical_interface(name))
             .map(|(name, data)| {
@@ -923,10 +2027,56 @@ impl MetricsCollector {
                 let tx = data.total_transmitted();
                 total_rx = total_rx.saturating_add(rx);
                 total_tx = total_tx.saturating_add(tx);
-                
+
                 // Try to get MAC address and speed
                 let (mac, speed) = Self::get_interface_details(name);
-                
+
+                let (rx_speed, tx_speed) = match last_interface_totals.get(name) {
+                    Some(&(prev_rx, prev_tx, prev_time)) => {
+                        let elapsed_secs = now.duration_since(prev_time).as_secs_f64();
+                        if elapsed_secs > 0.1 && rx >= prev_rx && tx >= prev_tx {
+                            (
+                                counter_rate(rx, prev_rx, elapsed_secs) as u64,
+                                counter_rate(tx, prev_tx, elapsed_secs) as u64,
+                            )
+                        } else {
+                            (0, 0)
+                        }
+                    }
+                    None => (0, 0),
+                };
+                last_interface_totals.insert(name.clone(), (rx, tx, now));
+
+                let (cur_rx_errors, cur_rx_dropped, cur_tx_errors, cur_tx_dropped) =
+                    Self::read_interface_error_counters(name, data);
+
+                let (rx_errors, rx_dropped, tx_errors, tx_dropped) =
+                    match last_interface_error_totals.get(name) {
+                        Some(&(prev_rx_errors, prev_rx_dropped, prev_tx_errors, prev_tx_dropped, prev_time)) => {
+                            let elapsed_secs = now.duration_since(prev_time).as_secs_f64();
+                            if elapsed_secs > 0.1
+                                && cur_rx_errors >= prev_rx_errors
+                                && cur_rx_dropped >= prev_rx_dropped
+                                && cur_tx_errors >= prev_tx_errors
+                                && cur_tx_dropped >= prev_tx_dropped
+                            {
+                                (
+                                    counter_rate(cur_rx_errors, prev_rx_errors, elapsed_secs) as u64,
+                                    counter_rate(cur_rx_dropped, prev_rx_dropped, elapsed_secs) as u64,
+                                    counter_rate(cur_tx_errors, prev_tx_errors, elapsed_secs) as u64,
+                                    counter_rate(cur_tx_dropped, prev_tx_dropped, elapsed_secs) as u64,
+                                )
+                            } else {
+                                (0, 0, 0, 0)
+                            }
+                        }
+                        None => (0, 0, 0, 0),
+                    };
+                last_interface_error_totals.insert(
+                    name.clone(),
+                    (cur_rx_errors, cur_rx_dropped, cur_tx_errors, cur_tx_dropped, now),
+                );
+
                 NetworkInterface {
                     name: name.to_string(),
                     mac,
@@ -935,51 +2085,282 @@ impl MetricsCollector {
                     tx_bytes: tx,
                     rx_packets: data.total_packets_received(),
                     tx_packets: data.total_packets_transmitted(),
+                    rx_speed,
+                    tx_speed,
+                    rx_errors,
+                    rx_dropped,
+                    tx_errors,
+                    tx_dropped,
                 }
             })
             .collect();
-        
+
         // Calculate speed (bytes per second)
-        let now = std::time::Instant::now();
-        let elapsed_secs = now.duration_since(self.last_network_time).as_secs_f64();
-        
-        let (rx_speed, tx_speed) = if elapsed_secs > 0.1 {
+        let elapsed_secs = now.duration_since(*last_network_time).as_secs_f64();
+
+        let net_dev_totals = Self::read_proc_net_dev_totals();
+        let snmp_totals = Self::read_proc_net_snmp_totals();
+
+        let (rx_speed, tx_speed, errors) = if elapsed_secs > 0.1 {
             // Only calculate if enough time has passed
-            let rx_diff = total_rx.saturating_sub(self.last_network_rx);
-            let tx_diff = total_tx.saturating_sub(self.last_network_tx);
-            
+            let rx_diff = total_rx.saturating_sub(*last_network_rx);
+            let tx_diff = total_tx.saturating_sub(*last_network_tx);
+
             // If totals went down (counter reset), use 0 for this interval
-            let rx_speed = if total_rx >= self.last_network_rx {
+            let rx_speed = if total_rx >= *last_network_rx {
                 (rx_diff as f64 / elapsed_secs) as u64
             } else {
                 0
             };
-            let tx_speed = if total_tx >= self.last_network_tx {
+            let tx_speed = if total_tx >= *last_network_tx {
                 (tx_diff as f64 / elapsed_secs) as u64
             } else {
                 0
             };
-            
+
+            // Error/protocol counters are only available once we have both a
+            // current and a previous reading from the proc files.
+            let errors = match (net_dev_totals, *last_net_dev_totals, snmp_totals, *last_snmp_totals) {
+                (Some(cur_dev), Some(prev_dev), Some(cur_snmp), Some(prev_snmp)) => Some(NetworkErrorStats {
+                    rx_errors_per_sec: counter_rate(cur_dev.rx_errors, prev_dev.rx_errors, elapsed_secs),
+                    rx_dropped_per_sec: counter_rate(cur_dev.rx_dropped, prev_dev.rx_dropped, elapsed_secs),
+                    rx_fifo_errors_per_sec: counter_rate(cur_dev.rx_fifo, prev_dev.rx_fifo, elapsed_secs),
+                    tx_errors_per_sec: counter_rate(cur_dev.tx_errors, prev_dev.tx_errors, elapsed_secs),
+                    tx_dropped_per_sec: counter_rate(cur_dev.tx_dropped, prev_dev.tx_dropped, elapsed_secs),
+                    tx_fifo_errors_per_sec: counter_rate(cur_dev.tx_fifo, prev_dev.tx_fifo, elapsed_secs),
+                    tx_collisions_per_sec: counter_rate(cur_dev.tx_collisions, prev_dev.tx_collisions, elapsed_secs),
+                    udp_in_errors_per_sec: counter_rate(cur_snmp.udp_in_errors, prev_snmp.udp_in_errors, elapsed_secs),
+                    udp_rcvbuf_errors_per_sec: counter_rate(cur_snmp.udp_rcvbuf_errors, prev_snmp.udp_rcvbuf_errors, elapsed_secs),
+                    udp_sndbuf_errors_per_sec: counter_rate(cur_snmp.udp_sndbuf_errors, prev_snmp.udp_sndbuf_errors, elapsed_secs),
+                    udp_no_ports_per_sec: counter_rate(cur_snmp.udp_no_ports, prev_snmp.udp_no_ports, elapsed_secs),
+                    udp_in_csum_errors_per_sec: counter_rate(cur_snmp.udp_in_csum_errors, prev_snmp.udp_in_csum_errors, elapsed_secs),
+                    tcp_retrans_segs_per_sec: counter_rate(cur_snmp.tcp_retrans_segs, prev_snmp.tcp_retrans_segs, elapsed_secs),
+                    udp_in_datagrams_per_sec: counter_rate(cur_snmp.udp_in_datagrams, prev_snmp.udp_in_datagrams, elapsed_secs),
+                    udp_out_datagrams_per_sec: counter_rate(cur_snmp.udp_out_datagrams, prev_snmp.udp_out_datagrams, elapsed_secs),
+                    tcp_in_errors_per_sec: counter_rate(cur_snmp.tcp_in_errors, prev_snmp.tcp_in_errors, elapsed_secs),
+                    totals: ProtocolTotals {
+                        rx_errors: cur_dev.rx_errors,
+                        rx_dropped: cur_dev.rx_dropped,
+                        rx_fifo_errors: cur_dev.rx_fifo,
+                        tx_errors: cur_dev.tx_errors,
+                        tx_dropped: cur_dev.tx_dropped,
+                        tx_fifo_errors: cur_dev.tx_fifo,
+                        tx_collisions: cur_dev.tx_collisions,
+                        udp_in_datagrams: cur_snmp.udp_in_datagrams,
+                        udp_out_datagrams: cur_snmp.udp_out_datagrams,
+                        udp_in_errors: cur_snmp.udp_in_errors,
+                        udp_rcvbuf_errors: cur_snmp.udp_rcvbuf_errors,
+                        udp_sndbuf_errors: cur_snmp.udp_sndbuf_errors,
+                        udp_no_ports: cur_snmp.udp_no_ports,
+                        udp_in_csum_errors: cur_snmp.udp_in_csum_errors,
+                        tcp_retrans_segs: cur_snmp.tcp_retrans_segs,
+                        tcp_in_errors: cur_snmp.tcp_in_errors,
+                    },
+                }),
+                _ => None,
+            };
+
             // Update tracking
-            self.last_network_rx = total_rx;
-            self.last_network_tx = total_tx;
-            self.last_network_time = now;
-            
-            (rx_speed, tx_speed)
+            *last_network_rx = total_rx;
+            *last_network_tx = total_tx;
+            *last_network_time = now;
+
+            (rx_speed, tx_speed, errors)
         } else {
             // Not enough time passed, return 0 to avoid spikes
-            (0, 0)
+            (0, 0, None)
         };
-        
+
+        if let Some(totals) = net_dev_totals {
+            *last_net_dev_totals = Some(totals);
+        }
+        if let Some(totals) = snmp_totals {
+            *last_snmp_totals = Some(totals);
+        }
+
         NetworkMetrics {
             interfaces,
             total_rx,
             total_tx,
             rx_speed,
             tx_speed,
+            errors,
+            limits,
         }
     }
-    
+
+    /// Sum per-interface error/drop/fifo/collision counters from
+    /// `/proc/net/dev` (skipping the two header lines and loopback). Each
+    /// line is `iface: rx_bytes rx_packets rx_errs rx_drop rx_fifo
+    /// rx_frame rx_compressed rx_multicast tx_bytes tx_packets tx_errs
+    /// tx_drop tx_fifo tx_colls tx_carrier tx_compressed`.
+    #[cfg(target_os = "linux")]
+    fn read_proc_net_dev_totals() -> Option<NetDevRawTotals> {
+        let content = std::fs::read_to_string("/proc/net/dev").ok()?;
+        let mut totals = NetDevRawTotals::default();
+
+        for line in content.lines().skip(2) {
+            let mut split = line.splitn(2, ':');
+            let name = split.next()?.trim();
+            let rest = split.next()?;
+            if name.is_empty() || name == "lo" {
+                continue;
+            }
+
+            let fields: Vec<u64> = rest
+                .split_whitespace()
+                .filter_map(|f| f.parse::<u64>().ok())
+                .collect();
+            if fields.len() < 16 {
+                continue;
+            }
+
+            totals.rx_errors = totals.rx_errors.saturating_add(fields[2]);
+            totals.rx_dropped = totals.rx_dropped.saturating_add(fields[3]);
+            totals.rx_fifo = totals.rx_fifo.saturating_add(fields[4]);
+            totals.tx_errors = totals.tx_errors.saturating_add(fields[10]);
+            totals.tx_dropped = totals.tx_dropped.saturating_add(fields[11]);
+            totals.tx_fifo = totals.tx_fifo.saturating_add(fields[12]);
+            totals.tx_collisions = totals.tx_collisions.saturating_add(fields[13]);
+        }
+
+        Some(totals)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read_proc_net_dev_totals() -> Option<NetDevRawTotals> {
+        None
+    }
+
+    /// Parse the `Udp:`/`Tcp:` rows of `/proc/net/snmp`, then fold in the
+    /// IPv6 UDP counters from `/proc/net/snmp6`: the first matching line of
+    /// `/proc/net/snmp` is a header naming each column, the next line holds
+    /// the values in the same order, so zip them into a name->value map
+    /// before pulling out the counters we care about. `/proc/net/snmp6` has
+    /// no separate `Tcp6:` table to merge in - the kernel's TCP counters in
+    /// `/proc/net/snmp` already count both address families - so only the
+    /// `Udp6*` keys need adding on top of the `Udp:` totals; otherwise an
+    /// IPv6-only or dual-stack host would silently report zero UDP traffic.
+    #[cfg(target_os = "linux")]
+    fn read_proc_net_snmp_totals() -> Option<SnmpRawTotals> {
+        let content = std::fs::read_to_string("/proc/net/snmp").ok()?;
+        let lines: Vec<&str> = content.lines().collect();
+        let mut totals = SnmpRawTotals::default();
+
+        for prefix in ["Udp:", "Tcp:"] {
+            let header_idx = lines.iter().position(|l| l.starts_with(prefix))?;
+            let values_line = lines.get(header_idx + 1)?;
+
+            let fields: std::collections::HashMap<&str, u64> = lines[header_idx]
+                .split_whitespace()
+                .skip(1)
+                .zip(values_line.split_whitespace().skip(1))
+                .filter_map(|(name, value)| value.parse::<u64>().ok().map(|value| (name, value)))
+                .collect();
+
+            if prefix == "Udp:" {
+                totals.udp_in_datagrams = fields.get("InDatagrams").copied().unwrap_or(0);
+                totals.udp_out_datagrams = fields.get("OutDatagrams").copied().unwrap_or(0);
+                totals.udp_in_errors = fields.get("InErrors").copied().unwrap_or(0);
+                totals.udp_rcvbuf_errors = fields.get("RcvbufErrors").copied().unwrap_or(0);
+                totals.udp_sndbuf_errors = fields.get("SndbufErrors").copied().unwrap_or(0);
+                totals.udp_no_ports = fields.get("NoPorts").copied().unwrap_or(0);
+                totals.udp_in_csum_errors = fields.get("InCsumErrors").copied().unwrap_or(0);
+            } else {
+                totals.tcp_retrans_segs = fields.get("RetransSegs").copied().unwrap_or(0);
+                totals.tcp_in_errors = fields.get("InErrs").copied().unwrap_or(0);
+            }
+        }
+
+        if let Some(udp6) = Self::read_proc_net_snmp6_udp_totals() {
+            totals.udp_in_datagrams += udp6.udp_in_datagrams;
+            totals.udp_out_datagrams += udp6.udp_out_datagrams;
+            totals.udp_in_errors += udp6.udp_in_errors;
+            totals.udp_rcvbuf_errors += udp6.udp_rcvbuf_errors;
+            totals.udp_sndbuf_errors += udp6.udp_sndbuf_errors;
+            totals.udp_no_ports += udp6.udp_no_ports;
+            totals.udp_in_csum_errors += udp6.udp_in_csum_errors;
+        }
+
+        Some(totals)
+    }
+
+    /// Parse the `Udp6*` keys of `/proc/net/snmp6`, which - unlike
+    /// `/proc/net/snmp` - is laid out as one `Key value` pair per line
+    /// rather than a header/values row pair. Returns `None` on hosts with
+    /// IPv6 disabled, where the file doesn't exist.
+    #[cfg(target_os = "linux")]
+    fn read_proc_net_snmp6_udp_totals() -> Option<SnmpRawTotals> {
+        let content = std::fs::read_to_string("/proc/net/snmp6").ok()?;
+        let fields: std::collections::HashMap<&str, u64> = content
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let key = parts.next()?;
+                let value = parts.next()?.parse::<u64>().ok()?;
+                Some((key, value))
+            })
+            .collect();
+
+        Some(SnmpRawTotals {
+            udp_in_datagrams: fields.get("Udp6InDatagrams").copied().unwrap_or(0),
+            udp_out_datagrams: fields.get("Udp6OutDatagrams").copied().unwrap_or(0),
+            udp_in_errors: fields.get("Udp6InErrors").copied().unwrap_or(0),
+            udp_rcvbuf_errors: fields.get("Udp6RcvbufErrors").copied().unwrap_or(0),
+            udp_sndbuf_errors: fields.get("Udp6SndbufErrors").copied().unwrap_or(0),
+            udp_no_ports: fields.get("Udp6NoPorts").copied().unwrap_or(0),
+            udp_in_csum_errors: fields.get("Udp6InCsumErrors").copied().unwrap_or(0),
+            ..Default::default()
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read_proc_net_snmp_totals() -> Option<SnmpRawTotals> {
+        None
+    }
+
+    /// Read a single `u64` out of a `/proc/sys` file, trimming the trailing
+    /// newline `proc` always appends.
+    #[cfg(target_os = "linux")]
+    fn read_proc_sys_u64(path: &str) -> Option<u64> {
+        std::fs::read_to_string(path).ok()?.trim().parse().ok()
+    }
+
+    /// Parse the `min default max` triple `/proc/sys/net/ipv4/tcp_{rmem,wmem}`
+    /// report as three whitespace-separated values.
+    #[cfg(target_os = "linux")]
+    fn read_tcp_mem_limits(path: &str) -> Option<TcpMemLimits> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let mut fields = content.trim().split_whitespace();
+        Some(TcpMemLimits {
+            min: fields.next()?.parse().ok()?,
+            default: fields.next()?.parse().ok()?,
+            max: fields.next()?.parse().ok()?,
+        })
+    }
+
+    /// Sample the kernel's network-buffer tunables from `/proc/sys/net`.
+    /// Individual fields are `None` when their file is missing or
+    /// unreadable rather than failing the whole read, since the set of
+    /// exposed `sysctl`s varies by kernel version and container runtime.
+    #[cfg(target_os = "linux")]
+    fn read_network_limits() -> Option<NetworkLimits> {
+        Some(NetworkLimits {
+            rmem_max: Self::read_proc_sys_u64("/proc/sys/net/core/rmem_max"),
+            wmem_max: Self::read_proc_sys_u64("/proc/sys/net/core/wmem_max"),
+            rmem_default: Self::read_proc_sys_u64("/proc/sys/net/core/rmem_default"),
+            wmem_default: Self::read_proc_sys_u64("/proc/sys/net/core/wmem_default"),
+            netdev_max_backlog: Self::read_proc_sys_u64("/proc/sys/net/core/netdev_max_backlog"),
+            tcp_rmem: Self::read_tcp_mem_limits("/proc/sys/net/ipv4/tcp_rmem"),
+            tcp_wmem: Self::read_tcp_mem_limits("/proc/sys/net/ipv4/tcp_wmem"),
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn read_network_limits() -> Option<NetworkLimits> {
+        None
+    }
+
     /// Check if a network interface is physical (not virtual/loopback)
     fn is_physical_interface(name: &str) -> bool {
         // Exclude loopback
@@ -1120,7 +2501,36 @@ impl MetricsCollector {
         
         (mac, speed)
     }
-    
+
+    /// Read an interface's cumulative error/drop counters: (rx_errors,
+    /// rx_dropped, tx_errors, tx_dropped). These are raw cumulative totals,
+    /// not rates — `sample_network` diffs them against a prior sample the
+    /// same way it does for `rx_bytes`/`tx_bytes`.
+    #[cfg(target_os = "linux")]
+    fn read_interface_error_counters(name: &str, _data: &sysinfo::NetworkData) -> (u64, u64, u64, u64) {
+        let read_stat = |stat: &str| -> u64 {
+            std::fs::read_to_string(format!("/sys/class/net/{}/statistics/{}", name, stat))
+                .ok()
+                .and_then(|s| s.trim().parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+
+        (
+            read_stat("rx_errors"),
+            read_stat("rx_dropped"),
+            read_stat("tx_errors"),
+            read_stat("tx_dropped"),
+        )
+    }
+
+    /// `sysinfo` doesn't expose per-interface drop counters on non-Linux
+    /// platforms, only error counters, so `rx_dropped`/`tx_dropped` stay 0
+    /// there.
+    #[cfg(not(target_os = "linux"))]
+    fn read_interface_error_counters(_name: &str, data: &sysinfo::NetworkData) -> (u64, u64, u64, u64) {
+        (data.errors_on_received(), 0, data.errors_on_transmitted(), 0)
+    }
+
     fn collect_load_average(&self) -> LoadAverage {
         let load = System::load_average();
         LoadAverage {
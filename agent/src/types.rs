@@ -0,0 +1,246 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A full point-in-time snapshot of a host's system metrics, serialized and
+/// sent to the server over the agent's websocket connection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemMetrics {
+    pub timestamp: DateTime<Utc>,
+    pub hostname: String,
+    pub os: OsInfo,
+    pub cpu: CpuMetrics,
+    pub memory: MemoryMetrics,
+    pub disks: Vec<DiskMetrics>,
+    pub network: NetworkMetrics,
+    pub uptime: u64,
+    pub load_average: LoadAverage,
+    pub ping: Option<PingMetrics>,
+    pub dns: Option<DnsMetrics>,
+    pub version: Option<String>,
+    pub ip_addresses: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OsInfo {
+    pub name: String,
+    pub version: String,
+    pub kernel: String,
+    pub arch: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CpuMetrics {
+    pub brand: String,
+    pub cores: usize,
+    pub usage: f32,
+    pub frequency: u64,
+    pub per_core: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemoryMetrics {
+    pub total: u64,
+    pub used: u64,
+    pub available: u64,
+    pub swap_total: u64,
+    pub swap_used: u64,
+    pub usage_percent: f32,
+    pub modules: Vec<MemoryModule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryModule {
+    pub slot: Option<String>,
+    pub size: u64,
+    pub mem_type: Option<String>,
+    pub speed: Option<u32>,
+    pub manufacturer: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskMetrics {
+    pub name: String,
+    pub model: Option<String>,
+    pub serial: Option<String>,
+    pub total: u64,
+    pub disk_type: Option<String>,
+    pub mount_points: Vec<String>,
+    pub usage_percent: f32,
+    pub used: u64,
+    pub read_bytes_per_sec: u64,
+    pub write_bytes_per_sec: u64,
+    pub read_iops: u64,
+    pub write_iops: u64,
+    pub read_latency_ms: Option<f64>,
+    pub write_latency_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkInterface {
+    pub name: String,
+    pub mac: Option<String>,
+    pub speed: Option<u32>,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_speed: u64,
+    pub tx_speed: u64,
+    pub rx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_errors: u64,
+    pub tx_dropped: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkMetrics {
+    pub interfaces: Vec<NetworkInterface>,
+    pub total_rx: u64,
+    pub total_tx: u64,
+    pub rx_speed: u64,
+    pub tx_speed: u64,
+    pub errors: Option<NetworkErrorStats>,
+    pub limits: Option<NetworkLimits>,
+}
+
+/// Kernel network-buffer tunables read from `/proc/sys/net/{core,ipv4}`.
+/// Sampled far less often than everything else on `NetworkMetrics` since
+/// these rarely change — cross-reference against `NetworkErrorStats.totals`
+/// (e.g. `udp_rcvbuf_errors` climbing while `rmem_max` sits at its default)
+/// to flag hosts that need their socket buffers raised before they start
+/// dropping traffic. `None` on non-Linux hosts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkLimits {
+    pub rmem_max: Option<u64>,
+    pub wmem_max: Option<u64>,
+    pub rmem_default: Option<u64>,
+    pub wmem_default: Option<u64>,
+    pub netdev_max_backlog: Option<u64>,
+    pub tcp_rmem: Option<TcpMemLimits>,
+    pub tcp_wmem: Option<TcpMemLimits>,
+}
+
+/// The min/default/max triple `/proc/sys/net/ipv4/tcp_{rmem,wmem}` report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TcpMemLimits {
+    pub min: u64,
+    pub default: u64,
+    pub max: u64,
+}
+
+/// Per-second network error/protocol counters, derived from `/proc/net/dev`
+/// (interface-level) and `/proc/net/snmp` (protocol-level). `None` on
+/// non-Linux hosts, or when the proc files can't be read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkErrorStats {
+    pub rx_errors_per_sec: f64,
+    pub rx_dropped_per_sec: f64,
+    pub rx_fifo_errors_per_sec: f64,
+    pub tx_errors_per_sec: f64,
+    pub tx_dropped_per_sec: f64,
+    pub tx_fifo_errors_per_sec: f64,
+    pub tx_collisions_per_sec: f64,
+    pub udp_in_errors_per_sec: f64,
+    pub udp_rcvbuf_errors_per_sec: f64,
+    pub udp_sndbuf_errors_per_sec: f64,
+    pub udp_no_ports_per_sec: f64,
+    pub udp_in_csum_errors_per_sec: f64,
+    pub tcp_retrans_segs_per_sec: f64,
+    pub udp_in_datagrams_per_sec: f64,
+    pub udp_out_datagrams_per_sec: f64,
+    pub tcp_in_errors_per_sec: f64,
+    pub totals: ProtocolTotals,
+}
+
+/// Cumulative (never-reset) counterparts of the `NetworkErrorStats` rates,
+/// read straight from `/proc/net/dev`/`/proc/net/snmp` with no windowing —
+/// useful for dashboards that want to compute their own longer-horizon
+/// rates instead of trusting the agent's sampling interval.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProtocolTotals {
+    pub rx_errors: u64,
+    pub rx_dropped: u64,
+    pub rx_fifo_errors: u64,
+    pub tx_errors: u64,
+    pub tx_dropped: u64,
+    pub tx_fifo_errors: u64,
+    pub tx_collisions: u64,
+    pub udp_in_datagrams: u64,
+    pub udp_out_datagrams: u64,
+    pub udp_in_errors: u64,
+    pub udp_rcvbuf_errors: u64,
+    pub udp_sndbuf_errors: u64,
+    pub udp_no_ports: u64,
+    pub udp_in_csum_errors: u64,
+    pub tcp_retrans_segs: u64,
+    pub tcp_in_errors: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingMetrics {
+    pub targets: Vec<PingTarget>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingTarget {
+    pub name: String,
+    pub host: String,
+    pub latency_ms: Option<f64>,
+    pub packet_loss: f64,
+    pub status: String,
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub probe: ProbeKind,
+}
+
+/// Resolution-latency metric complementing the ICMP/TCP ping targets: one
+/// result per resolver discovered from the host's network configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsMetrics {
+    pub resolvers: Vec<DnsResolverResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsResolverResult {
+    pub address: String,
+    pub resolve_ms: Option<f64>,
+    pub status: String,
+}
+
+/// A ping target as configured on a `RemoteServer` in the server's
+/// `AppConfig`, pushed down to the agent over the websocket connection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingTargetConfig {
+    pub name: String,
+    pub host: String,
+    /// Port to probe when `probe` is `Tcp`. Unused for `Icmp`.
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub probe: ProbeKind,
+}
+
+/// Which transport a `PingTargetConfig` is checked with: an ICMP `ping`
+/// (the original behavior, needs elevated privileges on many setups) or an
+/// in-process TCP connect-timeout probe against `host:port` (works
+/// unprivileged and verifies an actual service is accepting connections).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProbeKind {
+    Icmp,
+    Tcp,
+}
+
+impl Default for ProbeKind {
+    fn default() -> Self {
+        ProbeKind::Icmp
+    }
+}
@@ -11,6 +11,129 @@ pub struct AppConfig {
     pub servers: Vec<RemoteServer>,
     #[serde(default)]
     pub site_settings: SiteSettings,
+    /// If true, the Prometheus `/metrics` scrape endpoint is served without
+    /// the admin bearer-token check so an external Prometheus can hit it
+    /// directly. Defaults to false (guarded like the rest of the API).
+    #[serde(default)]
+    pub metrics_endpoint_public: bool,
+    /// Storage backend connection string. Empty/absent keeps the default
+    /// local SQLite file (`DB_FILE`); a `postgres://` URL switches
+    /// `store::connect` over to `PostgresStore` for shared, multi-instance
+    /// deployments.
+    #[serde(default)]
+    pub database_url: Option<String>,
+    /// Per-tier retention and downsampling policy. Drives `cleanup_old_data`
+    /// and the rollup jobs in `server::store` instead of the old hardcoded
+    /// 24h/30d/forever schedule.
+    #[serde(default)]
+    pub retention: RetentionPolicy,
+}
+
+/// How long each rollup tier is kept, and which of the optional coarser
+/// tiers (weekly, monthly) run at all. Each enabled tier must outlive the
+/// next finer tier (monthly is checked against weekly when weekly is
+/// enabled, otherwise against daily), with a `None` TTL counting as
+/// forever; `load_config` rejects policies that don't (e.g. a
+/// `daily_ttl_days` shorter than `hourly_ttl_days`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    /// How long raw, per-sample rows are kept, in hours.
+    #[serde(default = "default_raw_ttl_hours")]
+    pub raw_ttl_hours: i64,
+    /// How long hourly rollups are kept, in days.
+    #[serde(default = "default_hourly_ttl_days")]
+    pub hourly_ttl_days: i64,
+    /// How long daily rollups are kept, in days. `None` keeps them forever.
+    #[serde(default)]
+    pub daily_ttl_days: Option<i64>,
+    /// Whether the weekly rollup tier (downsampled from `metrics_daily`) runs.
+    #[serde(default)]
+    pub weekly_enabled: bool,
+    /// How long weekly rollups are kept, in days. `None` keeps them forever.
+    #[serde(default)]
+    pub weekly_ttl_days: Option<i64>,
+    /// Whether the monthly rollup tier (downsampled from `metrics_daily`) runs.
+    #[serde(default)]
+    pub monthly_enabled: bool,
+    /// How long monthly rollups are kept, in days. `None` keeps them forever.
+    #[serde(default)]
+    pub monthly_ttl_days: Option<i64>,
+}
+
+fn default_raw_ttl_hours() -> i64 {
+    24
+}
+
+fn default_hourly_ttl_days() -> i64 {
+    30
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            raw_ttl_hours: default_raw_ttl_hours(),
+            hourly_ttl_days: default_hourly_ttl_days(),
+            daily_ttl_days: None,
+            weekly_enabled: false,
+            weekly_ttl_days: None,
+            monthly_enabled: false,
+            monthly_ttl_days: None,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Checks that each enabled, coarser tier outlives the next finer tier,
+    /// counting a `None` TTL as forever. Returns a description of the first
+    /// violation found.
+    pub fn validate(&self) -> Result<(), String> {
+        let raw_ttl_days = self.raw_ttl_hours as f64 / 24.0;
+        if (self.hourly_ttl_days as f64) < raw_ttl_days {
+            return Err(format!(
+                "retention.hourly_ttl_days ({}) must be >= retention.raw_ttl_hours ({}h)",
+                self.hourly_ttl_days, self.raw_ttl_hours
+            ));
+        }
+        if let Some(daily_ttl_days) = self.daily_ttl_days {
+            if daily_ttl_days < self.hourly_ttl_days {
+                return Err(format!(
+                    "retention.daily_ttl_days ({daily_ttl_days}) must be >= retention.hourly_ttl_days ({})",
+                    self.hourly_ttl_days
+                ));
+            }
+        }
+        if self.weekly_enabled {
+            if let Some(weekly_ttl_days) = self.weekly_ttl_days {
+                if self.daily_ttl_days.map_or(true, |daily| weekly_ttl_days < daily) {
+                    let daily_floor = self.daily_ttl_days.map_or("forever".to_string(), |d| d.to_string());
+                    return Err(format!(
+                        "retention.weekly_ttl_days ({weekly_ttl_days}) must be >= retention.daily_ttl_days ({daily_floor})"
+                    ));
+                }
+            }
+        }
+        if self.monthly_enabled {
+            if let Some(monthly_ttl_days) = self.monthly_ttl_days {
+                let (floor_name, floor) = if self.weekly_enabled {
+                    ("weekly_ttl_days", self.weekly_ttl_days)
+                } else {
+                    ("daily_ttl_days", self.daily_ttl_days)
+                };
+                if floor.map_or(true, |floor| monthly_ttl_days < floor) {
+                    let floor = floor.map_or("forever".to_string(), |f| f.to_string());
+                    return Err(format!(
+                        "retention.monthly_ttl_days ({monthly_ttl_days}) must be >= retention.{floor_name} ({floor})"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Path to the local SQLite database file used when `database_url` isn't set.
+pub fn get_db_path() -> PathBuf {
+    PathBuf::from(DB_FILE)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -54,20 +177,38 @@ impl Default for AppConfig {
                 site_description: "Real-time Server Monitoring".to_string(),
                 social_links: vec![],
             },
+            metrics_endpoint_public: false,
+            database_url: None,
+            retention: RetentionPolicy::default(),
         }
     }
 }
 
-pub fn load_config() -> AppConfig {
+/// Loads `vstats-config.json`, creating it with defaults if absent.
+///
+/// Returns `Err` if the stored retention policy is inverted (e.g. a
+/// `daily_ttl_days` shorter than `hourly_ttl_days`) rather than silently
+/// substituting `RetentionPolicy::default()` — serving traffic under a
+/// retention policy the admin never configured would silently shorten data
+/// lifetimes with no visible error. Callers should refuse to start on `Err`.
+pub fn load_config() -> Result<AppConfig, String> {
     let path = PathBuf::from(CONFIG_FILE);
-    if path.exists() {
+    let config: AppConfig = if path.exists() {
         let content = fs::read_to_string(&path).unwrap_or_default();
         serde_json::from_str(&content).unwrap_or_default()
     } else {
         let config = AppConfig::default();
         save_config(&config);
         config
+    };
+
+    if let Err(reason) = config.retention.validate() {
+        return Err(format!(
+            "vstats-config.json has an inverted retention policy: {reason}"
+        ));
     }
+
+    Ok(config)
 }
 
 pub fn save_config(config: &AppConfig) {
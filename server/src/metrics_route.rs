@@ -0,0 +1,146 @@
+use std::fmt::Write as _;
+use std::sync::atomic::Ordering;
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+
+use crate::config::AppConfig;
+use crate::middleware::has_valid_bearer_token;
+use crate::state::AppState;
+use crate::types::SystemMetrics;
+
+/// `GET /metrics` - renders the latest per-server `SystemMetrics` plus
+/// internal ingest/aggregation counters in Prometheus text exposition
+/// format, so vstats can be scraped alongside the rest of a monitoring
+/// stack instead of relying solely on the websocket dashboard.
+///
+/// Gated by `AppConfig::metrics_endpoint_public`: when true the route is
+/// open for an external Prometheus to scrape directly; when false (the
+/// default) it enforces the same admin bearer-token check as
+/// `auth_middleware` itself, since this handler may be mounted without
+/// that middleware in front of it.
+pub async fn metrics_handler(
+    State(state): State<AppState>,
+    headers: axum::http::HeaderMap,
+) -> axum::response::Response {
+    let config = state.config.read().await;
+    if !config.metrics_endpoint_public && !has_valid_bearer_token(&headers) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    let agents = state.agent_metrics.read().await;
+    let connections = state.agent_connections.read().await;
+
+    let mut out = String::new();
+
+    writeln!(out, "# HELP vstats_cpu_usage Current CPU usage percent").ok();
+    writeln!(out, "# TYPE vstats_cpu_usage gauge").ok();
+    for (server_id, data) in agents.iter() {
+        let (location, provider) = server_labels(&config, server_id);
+        writeln!(
+            out,
+            "vstats_cpu_usage{{server_id=\"{}\",location=\"{}\",provider=\"{}\"}} {}",
+            escape_label_value(server_id), escape_label_value(&location), escape_label_value(&provider), data.metrics.cpu.usage
+        )
+        .ok();
+    }
+
+    writeln!(out, "# HELP vstats_memory_usage_percent Current memory usage percent").ok();
+    writeln!(out, "# TYPE vstats_memory_usage_percent gauge").ok();
+    for (server_id, data) in agents.iter() {
+        let (location, provider) = server_labels(&config, server_id);
+        writeln!(
+            out,
+            "vstats_memory_usage_percent{{server_id=\"{}\",location=\"{}\",provider=\"{}\"}} {}",
+            escape_label_value(server_id), escape_label_value(&location), escape_label_value(&provider), data.metrics.memory.usage_percent
+        )
+        .ok();
+    }
+
+    writeln!(out, "# HELP vstats_disk_usage_percent Usage percent of the first reported disk").ok();
+    writeln!(out, "# TYPE vstats_disk_usage_percent gauge").ok();
+    for (server_id, data) in agents.iter() {
+        let (location, provider) = server_labels(&config, server_id);
+        let usage = data.metrics.disks.first().map(|d| d.usage_percent).unwrap_or(0.0);
+        writeln!(
+            out,
+            "vstats_disk_usage_percent{{server_id=\"{}\",location=\"{}\",provider=\"{}\"}} {}",
+            escape_label_value(server_id), escape_label_value(&location), escape_label_value(&provider), usage
+        )
+        .ok();
+    }
+
+    writeln!(out, "# HELP vstats_ping_ms Average ping latency across configured targets").ok();
+    writeln!(out, "# TYPE vstats_ping_ms gauge").ok();
+    for (server_id, data) in agents.iter() {
+        if let Some(ping_ms) = average_ping(&data.metrics) {
+            let (location, provider) = server_labels(&config, server_id);
+            writeln!(
+                out,
+                "vstats_ping_ms{{server_id=\"{}\",location=\"{}\",provider=\"{}\"}} {}",
+                escape_label_value(server_id), escape_label_value(&location), escape_label_value(&provider), ping_ms
+            )
+            .ok();
+        }
+    }
+
+    writeln!(out, "# HELP vstats_connected_agents Agents currently holding an open websocket connection").ok();
+    writeln!(out, "# TYPE vstats_connected_agents gauge").ok();
+    writeln!(out, "vstats_connected_agents {}", connections.len()).ok();
+
+    writeln!(out, "# HELP vstats_tracked_servers Servers with at least one stored metrics sample").ok();
+    writeln!(out, "# TYPE vstats_tracked_servers gauge").ok();
+    writeln!(out, "vstats_tracked_servers {}", agents.len()).ok();
+
+    writeln!(out, "# HELP vstats_samples_ingested_total Total metrics samples ingested since startup").ok();
+    writeln!(out, "# TYPE vstats_samples_ingested_total counter").ok();
+    writeln!(
+        out,
+        "vstats_samples_ingested_total {}",
+        state.stats.samples_ingested.load(Ordering::Relaxed)
+    )
+    .ok();
+
+    writeln!(out, "# HELP vstats_aggregation_runs_total Aggregation passes run since startup, by tier").ok();
+    writeln!(out, "# TYPE vstats_aggregation_runs_total counter").ok();
+    writeln!(
+        out,
+        "vstats_aggregation_runs_total{{tier=\"hourly\"}} {}",
+        state.stats.hourly_aggregations_run.load(Ordering::Relaxed)
+    )
+    .ok();
+    writeln!(
+        out,
+        "vstats_aggregation_runs_total{{tier=\"daily\"}} {}",
+        state.stats.daily_aggregations_run.load(Ordering::Relaxed)
+    )
+    .ok();
+
+    ([("content-type", "text/plain; version=0.0.4")], out).into_response()
+}
+
+/// Escapes a Prometheus exposition-format label value per the text format
+/// spec: backslash, double quote and newline are the only characters that
+/// need escaping inside the quotes. `server_id`/`location`/`provider` are
+/// admin-supplied free text, so a stray `"` or embedded newline must not be
+/// allowed to corrupt the rest of the scrape payload.
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn server_labels(config: &AppConfig, server_id: &str) -> (String, String) {
+    config
+        .servers
+        .iter()
+        .find(|s| s.id == server_id)
+        .map(|s| (s.location.clone(), s.provider.clone()))
+        .unwrap_or_else(|| ("unknown".to_string(), "unknown".to_string()))
+}
+
+fn average_ping(metrics: &SystemMetrics) -> Option<f64> {
+    let ping = metrics.ping.as_ref()?;
+    let valid: Vec<f64> = ping.targets.iter().filter_map(|t| t.latency_ms).collect();
+    if valid.is_empty() {
+        None
+    } else {
+        Some(valid.iter().sum::<f64>() / valid.len() as f64)
+    }
+}
@@ -13,22 +13,29 @@ pub async fn auth_middleware(
     request: axum::extract::Request,
     next: Next,
 ) -> Response {
-    if let Some(auth) = headers
+    if has_valid_bearer_token(&headers) {
+        return next.run(request).await;
+    }
+    StatusCode::UNAUTHORIZED.into_response()
+}
+
+/// Checks the `Authorization` header for a `Bearer` token with a valid
+/// admin JWT. Shared with [`crate::metrics_route::metrics_handler`], which
+/// enforces this itself rather than relying on `auth_middleware` being
+/// wired in front of it.
+pub fn has_valid_bearer_token(headers: &axum::http::HeaderMap) -> bool {
+    headers
         .get(header::AUTHORIZATION)
         .and_then(|h| h.to_str().ok())
-    {
-        if let Some(token) = auth.strip_prefix("Bearer ") {
-            if decode::<Claims>(
+        .and_then(|auth| auth.strip_prefix("Bearer "))
+        .map(|token| {
+            decode::<Claims>(
                 token,
                 &DecodingKey::from_secret(JWT_SECRET.as_bytes()),
                 &Validation::default(),
             )
             .is_ok()
-            {
-                return next.run(request).await;
-            }
-        }
-    }
-    StatusCode::UNAUTHORIZED.into_response()
+        })
+        .unwrap_or(false)
 }
 
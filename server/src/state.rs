@@ -1,9 +1,15 @@
 use axum::extract::ws::Message;
-use rusqlite::Connection;
-use std::{collections::HashMap, sync::Arc};
-use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::{broadcast, mpsc, RwLock};
 
 use crate::config::AppConfig;
+use crate::store::MetricsStore;
 use crate::types::AgentMetricsData;
 
 /// Represents a connected agent's command channel
@@ -14,8 +20,33 @@ pub struct AppState {
     pub config: Arc<RwLock<AppConfig>>,
     pub metrics_tx: broadcast::Sender<String>,
     pub agent_metrics: Arc<RwLock<HashMap<String, AgentMetricsData>>>,
-    pub db: Arc<Mutex<Connection>>,
+    pub db: Arc<dyn MetricsStore>,
     /// Track connected agents by server_id -> command sender
     pub agent_connections: Arc<RwLock<HashMap<String, AgentCommandSender>>>,
+    /// Internal counters surfaced on the Prometheus `/metrics` endpoint
+    pub stats: Arc<AppStats>,
+}
+
+/// Process-lifetime counters that aren't worth a database round-trip to read.
+/// Incremented from the websocket ingest handler and the aggregation loop.
+#[derive(Default)]
+pub struct AppStats {
+    pub samples_ingested: AtomicU64,
+    pub hourly_aggregations_run: AtomicU64,
+    pub daily_aggregations_run: AtomicU64,
+}
+
+impl AppStats {
+    pub fn record_sample(&self) {
+        self.samples_ingested.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_hourly_aggregation(&self) {
+        self.hourly_aggregations_run.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_daily_aggregation(&self) {
+        self.daily_aggregations_run.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
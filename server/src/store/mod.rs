@@ -0,0 +1,120 @@
+mod postgres;
+mod sqlite;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::config::AppConfig;
+use crate::types::{
+    DailyAggregate, DiskDailyAggregate, DiskHourlyAggregate, HourlyAggregate, MonthlyAggregate,
+    Outage, SlaSummary, SystemMetrics, WeeklyAggregate,
+};
+
+pub use postgres::PostgresStore;
+pub use sqlite::SqliteStore;
+
+/// Storage backend for metrics ingestion, rollups and retention.
+///
+/// Replaces the old `Arc<Mutex<rusqlite::Connection>>` on `AppState`, which
+/// serialized every write through a single lock and pinned vstats to one
+/// local file. Implementations are free to use their own internal
+/// connection pooling/locking strategy.
+#[async_trait]
+pub trait MetricsStore: Send + Sync {
+    /// Insert a single raw sample for `server_id`.
+    async fn store_metrics(&self, server_id: &str, metrics: &SystemMetrics) -> anyhow::Result<()>;
+
+    /// Roll the last completed hour of `metrics_raw` into `metrics_hourly`.
+    async fn aggregate_hourly(&self) -> anyhow::Result<()>;
+
+    /// Roll the last completed day of `metrics_hourly` into `metrics_daily`.
+    async fn aggregate_daily(&self) -> anyhow::Result<()>;
+
+    /// Roll the last completed week of `metrics_daily` into `metrics_weekly`.
+    /// A no-op when `RetentionPolicy::weekly_enabled` is false.
+    async fn aggregate_weekly(&self) -> anyhow::Result<()>;
+
+    /// Roll the last completed month of `metrics_daily` into `metrics_monthly`.
+    /// A no-op when `RetentionPolicy::monthly_enabled` is false.
+    async fn aggregate_monthly(&self) -> anyhow::Result<()>;
+
+    /// Delete rows older than each tier's `RetentionPolicy` TTL.
+    async fn cleanup_old_data(&self) -> anyhow::Result<()>;
+
+    /// Open or close `outages` rows by scanning, per server, every sample in
+    /// the trailing scan window (matching the `aggregate_hourly` cadence)
+    /// for stretches where ingestion went silent for longer than the
+    /// silence threshold or every ping target was unreachable — not just
+    /// the single most recent sample, so an outage that starts and resolves
+    /// within one window is still recorded. Intended to run on the same
+    /// cadence as `aggregate_hourly`.
+    async fn detect_outages(&self) -> anyhow::Result<()>;
+
+    /// List outages for `server_id` that started at or after `since`.
+    async fn outage_history(
+        &self,
+        server_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<Outage>>;
+
+    /// Rolling-window availability for `server_id` over the trailing `days`,
+    /// computed from `outages` (e.g. `days = 30` or `90` for the SLA page).
+    async fn sla_summary(&self, server_id: &str, days: i64) -> anyhow::Result<SlaSummary>;
+
+    /// Fetch hourly rollups for `server_id` at or after `since`.
+    async fn hourly_history(
+        &self,
+        server_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<HourlyAggregate>>;
+
+    /// Fetch daily rollups for `server_id` at or after `since`.
+    async fn daily_history(
+        &self,
+        server_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<DailyAggregate>>;
+
+    /// Fetch per-mount hourly disk rollups for `server_id` at or after `since`.
+    async fn disk_hourly_history(
+        &self,
+        server_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<DiskHourlyAggregate>>;
+
+    /// Fetch per-mount daily disk rollups for `server_id` at or after `since`.
+    async fn disk_daily_history(
+        &self,
+        server_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<DiskDailyAggregate>>;
+
+    /// Fetch weekly rollups for `server_id` at or after `since`.
+    async fn weekly_history(
+        &self,
+        server_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<WeeklyAggregate>>;
+
+    /// Fetch monthly rollups for `server_id` at or after `since`.
+    async fn monthly_history(
+        &self,
+        server_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<MonthlyAggregate>>;
+}
+
+/// Select and initialize a storage backend based on `AppConfig::database_url`.
+/// A missing/empty `database_url` keeps the existing local-SQLite behavior;
+/// a `postgres://` URL switches to the shared Postgres backend.
+pub async fn connect(config: &AppConfig) -> anyhow::Result<Arc<dyn MetricsStore>> {
+    match config.database_url.as_deref() {
+        Some(url) if url.starts_with("postgres://") || url.starts_with("postgresql://") => {
+            Ok(Arc::new(
+                PostgresStore::connect(url, config.retention.clone()).await?,
+            ))
+        }
+        _ => Ok(Arc::new(SqliteStore::open(config.retention.clone())?)),
+    }
+}
@@ -0,0 +1,1044 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{postgres::PgPoolOptions, PgPool, Row};
+
+use crate::config::RetentionPolicy;
+use crate::types::{
+    DailyAggregate, DiskDailyAggregate, DiskHourlyAggregate, HourlyAggregate, MonthlyAggregate,
+    Outage, SlaSummary, SystemMetrics, WeeklyAggregate,
+};
+
+use super::MetricsStore;
+
+/// How long a server can go without a new sample before it's considered
+/// down. Generous relative to the agent's ~10s reporting interval so a
+/// single dropped websocket frame doesn't open a spurious outage.
+const OUTAGE_SILENCE_THRESHOLD_SECONDS: i64 = 120;
+
+/// How far back `detect_outages` scans `metrics_raw`/`ping_targets_raw` on
+/// each run, in hours. Matches the `aggregate_hourly` cadence so an outage
+/// that opens and fully resolves between two runs is still observed in the
+/// window, instead of only the single most recent sample.
+const OUTAGE_SCAN_WINDOW_HOURS: i64 = 1;
+
+/// `MetricsStore` backed by Postgres via `sqlx`, for deployments that want a
+/// shared external database instead of one SQLite file per instance. Pooled
+/// connections mean writes no longer serialize through a single mutex.
+pub struct PostgresStore {
+    pool: PgPool,
+    retention: RetentionPolicy,
+}
+
+impl PostgresStore {
+    pub async fn connect(database_url: &str, retention: RetentionPolicy) -> anyhow::Result<Self> {
+        let pool = PgPoolOptions::new()
+            .max_connections(10)
+            .connect(database_url)
+            .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS metrics_raw (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                timestamp TIMESTAMPTZ NOT NULL,
+                cpu_usage DOUBLE PRECISION NOT NULL,
+                memory_usage DOUBLE PRECISION NOT NULL,
+                disk_usage DOUBLE PRECISION NOT NULL,
+                net_rx BIGINT NOT NULL,
+                net_tx BIGINT NOT NULL,
+                load_1 DOUBLE PRECISION NOT NULL,
+                load_5 DOUBLE PRECISION NOT NULL,
+                load_15 DOUBLE PRECISION NOT NULL,
+                ping_ms DOUBLE PRECISION,
+                created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS metrics_hourly (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                hour_start TIMESTAMPTZ NOT NULL,
+                cpu_avg DOUBLE PRECISION NOT NULL,
+                cpu_max DOUBLE PRECISION NOT NULL,
+                cpu_p95 DOUBLE PRECISION,
+                memory_avg DOUBLE PRECISION NOT NULL,
+                memory_max DOUBLE PRECISION NOT NULL,
+                disk_avg DOUBLE PRECISION NOT NULL,
+                net_rx_total BIGINT NOT NULL,
+                net_tx_total BIGINT NOT NULL,
+                ping_avg DOUBLE PRECISION,
+                ping_p50 DOUBLE PRECISION,
+                ping_p95 DOUBLE PRECISION,
+                ping_p99 DOUBLE PRECISION,
+                sample_count BIGINT NOT NULL,
+                UNIQUE(server_id, hour_start)
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS metrics_daily (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                date DATE NOT NULL,
+                cpu_avg DOUBLE PRECISION NOT NULL,
+                cpu_max DOUBLE PRECISION NOT NULL,
+                cpu_p95 DOUBLE PRECISION,
+                memory_avg DOUBLE PRECISION NOT NULL,
+                memory_max DOUBLE PRECISION NOT NULL,
+                disk_avg DOUBLE PRECISION NOT NULL,
+                net_rx_total BIGINT NOT NULL,
+                net_tx_total BIGINT NOT NULL,
+                uptime_percent DOUBLE PRECISION NOT NULL,
+                ping_avg DOUBLE PRECISION,
+                ping_p50 DOUBLE PRECISION,
+                ping_p95 DOUBLE PRECISION,
+                ping_p99 DOUBLE PRECISION,
+                sample_count BIGINT NOT NULL,
+                UNIQUE(server_id, date)
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS metrics_disks_raw (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                timestamp TIMESTAMPTZ NOT NULL,
+                mount_point TEXT NOT NULL,
+                disk_name TEXT NOT NULL,
+                usage_percent DOUBLE PRECISION NOT NULL,
+                total_bytes BIGINT NOT NULL,
+                available_bytes BIGINT NOT NULL,
+                UNIQUE(server_id, timestamp, mount_point)
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS metrics_disks_hourly (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                mount_point TEXT NOT NULL,
+                hour_start TIMESTAMPTZ NOT NULL,
+                usage_avg DOUBLE PRECISION NOT NULL,
+                available_min BIGINT NOT NULL,
+                sample_count BIGINT NOT NULL,
+                UNIQUE(server_id, mount_point, hour_start)
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS metrics_disks_daily (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                mount_point TEXT NOT NULL,
+                date DATE NOT NULL,
+                usage_avg DOUBLE PRECISION NOT NULL,
+                available_min BIGINT NOT NULL,
+                sample_count BIGINT NOT NULL,
+                UNIQUE(server_id, mount_point, date)
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_metrics_raw_server_time ON metrics_raw(server_id, timestamp)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_metrics_hourly_server_time ON metrics_hourly(server_id, hour_start)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_metrics_daily_server_time ON metrics_daily(server_id, date)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_metrics_disks_raw_server_time ON metrics_disks_raw(server_id, timestamp)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_metrics_disks_hourly_server_time ON metrics_disks_hourly(server_id, hour_start)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_metrics_disks_daily_server_time ON metrics_disks_daily(server_id, date)")
+            .execute(&pool)
+            .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS metrics_weekly (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                week_start DATE NOT NULL,
+                cpu_avg DOUBLE PRECISION NOT NULL,
+                cpu_max DOUBLE PRECISION NOT NULL,
+                memory_avg DOUBLE PRECISION NOT NULL,
+                memory_max DOUBLE PRECISION NOT NULL,
+                disk_avg DOUBLE PRECISION NOT NULL,
+                net_rx_total BIGINT NOT NULL,
+                net_tx_total BIGINT NOT NULL,
+                uptime_percent DOUBLE PRECISION NOT NULL,
+                ping_avg DOUBLE PRECISION,
+                sample_count BIGINT NOT NULL,
+                UNIQUE(server_id, week_start)
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS metrics_monthly (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                month_start DATE NOT NULL,
+                cpu_avg DOUBLE PRECISION NOT NULL,
+                cpu_max DOUBLE PRECISION NOT NULL,
+                memory_avg DOUBLE PRECISION NOT NULL,
+                memory_max DOUBLE PRECISION NOT NULL,
+                disk_avg DOUBLE PRECISION NOT NULL,
+                net_rx_total BIGINT NOT NULL,
+                net_tx_total BIGINT NOT NULL,
+                uptime_percent DOUBLE PRECISION NOT NULL,
+                ping_avg DOUBLE PRECISION,
+                sample_count BIGINT NOT NULL,
+                UNIQUE(server_id, month_start)
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_metrics_weekly_server_time ON metrics_weekly(server_id, week_start)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_metrics_monthly_server_time ON metrics_monthly(server_id, month_start)")
+            .execute(&pool)
+            .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS ping_targets_raw (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                timestamp TIMESTAMPTZ NOT NULL,
+                target_name TEXT NOT NULL,
+                target_host TEXT NOT NULL,
+                latency_ms DOUBLE PRECISION,
+                packet_loss DOUBLE PRECISION NOT NULL,
+                reachable BOOLEAN NOT NULL,
+                UNIQUE(server_id, timestamp, target_name)
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"CREATE TABLE IF NOT EXISTS outages (
+                id BIGSERIAL PRIMARY KEY,
+                server_id TEXT NOT NULL,
+                start TIMESTAMPTZ NOT NULL,
+                "end" TIMESTAMPTZ,
+                duration_seconds BIGINT
+            )"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_ping_targets_raw_server_time ON ping_targets_raw(server_id, timestamp)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_outages_server_start ON outages(server_id, start)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_outages_open ON outages(server_id, \"end\")")
+            .execute(&pool)
+            .await?;
+
+        Ok(Self { pool, retention })
+    }
+}
+
+#[async_trait]
+impl MetricsStore for PostgresStore {
+    async fn store_metrics(&self, server_id: &str, metrics: &SystemMetrics) -> anyhow::Result<()> {
+        let disk_usage = metrics.disks.first().map(|d| d.usage_percent).unwrap_or(0.0) as f64;
+        let ping_ms: Option<f64> = metrics.ping.as_ref().and_then(|p| {
+            let valid: Vec<f64> = p.targets.iter().filter_map(|t| t.latency_ms).collect();
+            if valid.is_empty() {
+                None
+            } else {
+                Some(valid.iter().sum::<f64>() / valid.len() as f64)
+            }
+        });
+
+        sqlx::query(
+            r#"INSERT INTO metrics_raw (server_id, timestamp, cpu_usage, memory_usage, disk_usage, net_rx, net_tx, load_1, load_5, load_15, ping_ms)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"#,
+        )
+        .bind(server_id)
+        .bind(metrics.timestamp)
+        .bind(metrics.cpu.usage as f64)
+        .bind(metrics.memory.usage_percent as f64)
+        .bind(disk_usage)
+        .bind(metrics.network.total_rx as i64)
+        .bind(metrics.network.total_tx as i64)
+        .bind(metrics.load_average.one)
+        .bind(metrics.load_average.five)
+        .bind(metrics.load_average.fifteen)
+        .bind(ping_ms)
+        .execute(&self.pool)
+        .await?;
+
+        // Record every ping target individually so outage detection can see
+        // reachability per target instead of only the blended `ping_ms` above.
+        if let Some(ping) = &metrics.ping {
+            for target in &ping.targets {
+                // "degraded" (some but not all probe attempts succeeded) still
+                // got a response, so it counts as reachable for outage
+                // purposes; only "timeout"/"refused"/"error" mean the target
+                // didn't answer at all.
+                let reachable = target.status == "ok" || target.status == "degraded";
+                sqlx::query(
+                    r#"INSERT INTO ping_targets_raw (server_id, timestamp, target_name, target_host, latency_ms, packet_loss, reachable)
+                       VALUES ($1, $2, $3, $4, $5, $6, $7)
+                       ON CONFLICT (server_id, timestamp, target_name) DO UPDATE SET
+                           latency_ms = EXCLUDED.latency_ms,
+                           packet_loss = EXCLUDED.packet_loss,
+                           reachable = EXCLUDED.reachable"#,
+                )
+                .bind(server_id)
+                .bind(metrics.timestamp)
+                .bind(&target.name)
+                .bind(&target.host)
+                .bind(target.latency_ms)
+                .bind(target.packet_loss)
+                .bind(reachable)
+                .execute(&self.pool)
+                .await?;
+            }
+        }
+
+        // Record every disk individually (keyed by mount point) so multi-mount
+        // servers and absolute free-space trends aren't collapsed into the
+        // single `disk_usage` figure above.
+        for disk in &metrics.disks {
+            let available_bytes = disk.total.saturating_sub(disk.used);
+            let mount_points: Vec<&str> = if disk.mount_points.is_empty() {
+                vec![disk.name.as_str()]
+            } else {
+                disk.mount_points.iter().map(|m| m.as_str()).collect()
+            };
+            for mount_point in mount_points {
+                sqlx::query(
+                    r#"INSERT INTO metrics_disks_raw (server_id, timestamp, mount_point, disk_name, usage_percent, total_bytes, available_bytes)
+                       VALUES ($1, $2, $3, $4, $5, $6, $7)
+                       ON CONFLICT (server_id, timestamp, mount_point) DO UPDATE SET
+                           disk_name = EXCLUDED.disk_name,
+                           usage_percent = EXCLUDED.usage_percent,
+                           total_bytes = EXCLUDED.total_bytes,
+                           available_bytes = EXCLUDED.available_bytes"#,
+                )
+                .bind(server_id)
+                .bind(metrics.timestamp)
+                .bind(mount_point)
+                .bind(&disk.name)
+                .bind(disk.usage_percent as f64)
+                .bind(disk.total as i64)
+                .bind(available_bytes as i64)
+                .execute(&self.pool)
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn aggregate_hourly(&self) -> anyhow::Result<()> {
+        // Postgres has a built-in discrete-percentile aggregate, so p95 can
+        // be folded into the same grouped INSERT instead of a second pass.
+        // `percentile_disc` (not `percentile_cont`) deliberately: it's the
+        // nearest-rank method, matching SQLite's `nearest_rank_percentile`
+        // exactly, so switching `database_url` between backends can't
+        // change historical percentile values for the same raw samples.
+        let hour_ago = Utc::now() - Duration::hours(1);
+        sqlx::query(
+            r#"INSERT INTO metrics_hourly (server_id, hour_start, cpu_avg, cpu_max, cpu_p95, memory_avg, memory_max, disk_avg, net_rx_total, net_tx_total, ping_avg, ping_p50, ping_p95, ping_p99, sample_count)
+               SELECT
+                   server_id,
+                   date_trunc('hour', timestamp),
+                   AVG(cpu_usage), MAX(cpu_usage),
+                   percentile_disc(0.95) WITHIN GROUP (ORDER BY cpu_usage),
+                   AVG(memory_usage), MAX(memory_usage),
+                   AVG(disk_usage),
+                   MAX(net_rx) - MIN(net_rx),
+                   MAX(net_tx) - MIN(net_tx),
+                   AVG(ping_ms) FILTER (WHERE ping_ms IS NOT NULL),
+                   percentile_disc(0.50) WITHIN GROUP (ORDER BY ping_ms) FILTER (WHERE ping_ms IS NOT NULL),
+                   percentile_disc(0.95) WITHIN GROUP (ORDER BY ping_ms) FILTER (WHERE ping_ms IS NOT NULL),
+                   percentile_disc(0.99) WITHIN GROUP (ORDER BY ping_ms) FILTER (WHERE ping_ms IS NOT NULL),
+                   COUNT(*)
+               FROM metrics_raw
+               WHERE timestamp >= date_trunc('hour', $1::timestamptz) AND timestamp < date_trunc('hour', $1::timestamptz) + interval '1 hour'
+               GROUP BY server_id, date_trunc('hour', timestamp)
+               ON CONFLICT (server_id, hour_start) DO UPDATE SET
+                   cpu_avg = EXCLUDED.cpu_avg, cpu_max = EXCLUDED.cpu_max, cpu_p95 = EXCLUDED.cpu_p95,
+                   memory_avg = EXCLUDED.memory_avg, memory_max = EXCLUDED.memory_max,
+                   disk_avg = EXCLUDED.disk_avg,
+                   net_rx_total = EXCLUDED.net_rx_total, net_tx_total = EXCLUDED.net_tx_total,
+                   ping_avg = EXCLUDED.ping_avg, ping_p50 = EXCLUDED.ping_p50, ping_p95 = EXCLUDED.ping_p95, ping_p99 = EXCLUDED.ping_p99,
+                   sample_count = EXCLUDED.sample_count"#,
+        )
+        .bind(hour_ago)
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"INSERT INTO metrics_disks_hourly (server_id, mount_point, hour_start, usage_avg, available_min, sample_count)
+               SELECT
+                   server_id,
+                   mount_point,
+                   date_trunc('hour', timestamp),
+                   AVG(usage_percent),
+                   MIN(available_bytes),
+                   COUNT(*)
+               FROM metrics_disks_raw
+               WHERE timestamp >= date_trunc('hour', $1::timestamptz) AND timestamp < date_trunc('hour', $1::timestamptz) + interval '1 hour'
+               GROUP BY server_id, mount_point, date_trunc('hour', timestamp)
+               ON CONFLICT (server_id, mount_point, hour_start) DO UPDATE SET
+                   usage_avg = EXCLUDED.usage_avg,
+                   available_min = EXCLUDED.available_min,
+                   sample_count = EXCLUDED.sample_count"#,
+        )
+        .bind(hour_ago)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn aggregate_daily(&self) -> anyhow::Result<()> {
+        let yesterday = Utc::now() - Duration::days(1);
+        sqlx::query(
+            r#"INSERT INTO metrics_daily (server_id, date, cpu_avg, cpu_max, cpu_p95, memory_avg, memory_max, disk_avg, net_rx_total, net_tx_total, uptime_percent, ping_avg, ping_p50, ping_p95, ping_p99, sample_count)
+               SELECT
+                   server_id,
+                   date(hour_start),
+                   AVG(cpu_avg), MAX(cpu_max), AVG(cpu_p95),
+                   AVG(memory_avg), MAX(memory_max),
+                   AVG(disk_avg),
+                   SUM(net_rx_total), SUM(net_tx_total),
+                   (COUNT(*) * 100.0 / 24.0),
+                   AVG(ping_avg), AVG(ping_p50), AVG(ping_p95), AVG(ping_p99),
+                   SUM(sample_count)
+               FROM metrics_hourly
+               WHERE date(hour_start) = date($1::timestamptz)
+               GROUP BY server_id, date(hour_start)
+               ON CONFLICT (server_id, date) DO UPDATE SET
+                   cpu_avg = EXCLUDED.cpu_avg, cpu_max = EXCLUDED.cpu_max, cpu_p95 = EXCLUDED.cpu_p95,
+                   memory_avg = EXCLUDED.memory_avg, memory_max = EXCLUDED.memory_max,
+                   disk_avg = EXCLUDED.disk_avg,
+                   net_rx_total = EXCLUDED.net_rx_total, net_tx_total = EXCLUDED.net_tx_total,
+                   uptime_percent = EXCLUDED.uptime_percent,
+                   ping_avg = EXCLUDED.ping_avg, ping_p50 = EXCLUDED.ping_p50, ping_p95 = EXCLUDED.ping_p95, ping_p99 = EXCLUDED.ping_p99,
+                   sample_count = EXCLUDED.sample_count"#,
+        )
+        .bind(yesterday)
+        .execute(&self.pool)
+        .await?;
+
+        // Replace the crude sample_count/24 uptime estimate above with real
+        // availability derived from `outages` overlapping this calendar day.
+        let day_start = yesterday.date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let day_end = day_start + Duration::days(1);
+        sqlx::query(
+            r#"UPDATE metrics_daily d
+               SET uptime_percent = GREATEST(0.0,
+                   100.0 * (
+                       extract(epoch FROM ($3::timestamptz - $2::timestamptz)) - COALESCE((
+                           SELECT SUM(
+                               extract(epoch FROM (LEAST(COALESCE(o."end", $3::timestamptz), $3::timestamptz)
+                                                   - GREATEST(o.start, $2::timestamptz)))
+                           )
+                           FROM outages o
+                           WHERE o.server_id = d.server_id
+                             AND o.start < $3::timestamptz
+                             AND (o."end" IS NULL OR o."end" > $2::timestamptz)
+                       ), 0)
+                   ) / extract(epoch FROM ($3::timestamptz - $2::timestamptz))
+               )
+               WHERE d.date = $1::date"#,
+        )
+        .bind(yesterday)
+        .bind(day_start)
+        .bind(day_end)
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"INSERT INTO metrics_disks_daily (server_id, mount_point, date, usage_avg, available_min, sample_count)
+               SELECT
+                   server_id,
+                   mount_point,
+                   date(hour_start),
+                   AVG(usage_avg),
+                   MIN(available_min),
+                   SUM(sample_count)
+               FROM metrics_disks_hourly
+               WHERE date(hour_start) = date($1::timestamptz)
+               GROUP BY server_id, mount_point, date(hour_start)
+               ON CONFLICT (server_id, mount_point, date) DO UPDATE SET
+                   usage_avg = EXCLUDED.usage_avg,
+                   available_min = EXCLUDED.available_min,
+                   sample_count = EXCLUDED.sample_count"#,
+        )
+        .bind(yesterday)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn aggregate_weekly(&self) -> anyhow::Result<()> {
+        if !self.retention.weekly_enabled {
+            return Ok(());
+        }
+        sqlx::query(
+            r#"INSERT INTO metrics_weekly (server_id, week_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg, net_rx_total, net_tx_total, uptime_percent, ping_avg, sample_count)
+               SELECT
+                   server_id,
+                   date_trunc('week', date)::date,
+                   AVG(cpu_avg), MAX(cpu_max),
+                   AVG(memory_avg), MAX(memory_max),
+                   AVG(disk_avg),
+                   SUM(net_rx_total), SUM(net_tx_total),
+                   AVG(uptime_percent),
+                   AVG(ping_avg),
+                   SUM(sample_count)
+               FROM metrics_daily
+               WHERE date_trunc('week', date) = date_trunc('week', (now() - interval '1 week'))
+               GROUP BY server_id, date_trunc('week', date)
+               ON CONFLICT (server_id, week_start) DO UPDATE SET
+                   cpu_avg = EXCLUDED.cpu_avg, cpu_max = EXCLUDED.cpu_max,
+                   memory_avg = EXCLUDED.memory_avg, memory_max = EXCLUDED.memory_max,
+                   disk_avg = EXCLUDED.disk_avg,
+                   net_rx_total = EXCLUDED.net_rx_total, net_tx_total = EXCLUDED.net_tx_total,
+                   uptime_percent = EXCLUDED.uptime_percent, ping_avg = EXCLUDED.ping_avg,
+                   sample_count = EXCLUDED.sample_count"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn aggregate_monthly(&self) -> anyhow::Result<()> {
+        if !self.retention.monthly_enabled {
+            return Ok(());
+        }
+        sqlx::query(
+            r#"INSERT INTO metrics_monthly (server_id, month_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg, net_rx_total, net_tx_total, uptime_percent, ping_avg, sample_count)
+               SELECT
+                   server_id,
+                   date_trunc('month', date)::date,
+                   AVG(cpu_avg), MAX(cpu_max),
+                   AVG(memory_avg), MAX(memory_max),
+                   AVG(disk_avg),
+                   SUM(net_rx_total), SUM(net_tx_total),
+                   AVG(uptime_percent),
+                   AVG(ping_avg),
+                   SUM(sample_count)
+               FROM metrics_daily
+               WHERE date_trunc('month', date) = date_trunc('month', (now() - interval '1 month'))
+               GROUP BY server_id, date_trunc('month', date)
+               ON CONFLICT (server_id, month_start) DO UPDATE SET
+                   cpu_avg = EXCLUDED.cpu_avg, cpu_max = EXCLUDED.cpu_max,
+                   memory_avg = EXCLUDED.memory_avg, memory_max = EXCLUDED.memory_max,
+                   disk_avg = EXCLUDED.disk_avg,
+                   net_rx_total = EXCLUDED.net_rx_total, net_tx_total = EXCLUDED.net_tx_total,
+                   uptime_percent = EXCLUDED.uptime_percent, ping_avg = EXCLUDED.ping_avg,
+                   sample_count = EXCLUDED.sample_count"#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn cleanup_old_data(&self) -> anyhow::Result<()> {
+        let cutoff_raw = Utc::now() - Duration::hours(self.retention.raw_ttl_hours);
+        sqlx::query("DELETE FROM metrics_raw WHERE timestamp < $1")
+            .bind(cutoff_raw)
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM metrics_disks_raw WHERE timestamp < $1")
+            .bind(cutoff_raw)
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM ping_targets_raw WHERE timestamp < $1")
+            .bind(cutoff_raw)
+            .execute(&self.pool)
+            .await?;
+
+        let cutoff_hourly = Utc::now() - Duration::days(self.retention.hourly_ttl_days);
+        sqlx::query("DELETE FROM metrics_hourly WHERE hour_start < $1")
+            .bind(cutoff_hourly)
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM metrics_disks_hourly WHERE hour_start < $1")
+            .bind(cutoff_hourly)
+            .execute(&self.pool)
+            .await?;
+
+        if let Some(daily_ttl_days) = self.retention.daily_ttl_days {
+            let cutoff_daily = (Utc::now() - Duration::days(daily_ttl_days)).date_naive();
+            sqlx::query("DELETE FROM metrics_daily WHERE date < $1")
+                .bind(cutoff_daily)
+                .execute(&self.pool)
+                .await?;
+            sqlx::query("DELETE FROM metrics_disks_daily WHERE date < $1")
+                .bind(cutoff_daily)
+                .execute(&self.pool)
+                .await?;
+        }
+        if let Some(weekly_ttl_days) = self.retention.weekly_ttl_days {
+            let cutoff_weekly = (Utc::now() - Duration::days(weekly_ttl_days)).date_naive();
+            sqlx::query("DELETE FROM metrics_weekly WHERE week_start < $1")
+                .bind(cutoff_weekly)
+                .execute(&self.pool)
+                .await?;
+        }
+        if let Some(monthly_ttl_days) = self.retention.monthly_ttl_days {
+            let cutoff_monthly = (Utc::now() - Duration::days(monthly_ttl_days)).date_naive();
+            sqlx::query("DELETE FROM metrics_monthly WHERE month_start < $1")
+                .bind(cutoff_monthly)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Scans every sample in the trailing [`OUTAGE_SCAN_WINDOW_HOURS`]
+    /// window (plus the one sample immediately before it, as a baseline for
+    /// gap detection) rather than only the latest row, so an outage that
+    /// both starts and resolves inside that window is still recorded
+    /// instead of silently skipped. A moment is "down" when every ping
+    /// target sampled at it was unreachable, or when the gap since the
+    /// previous sample (or, at the tail of the window, since now) exceeds
+    /// [`OUTAGE_SILENCE_THRESHOLD_SECONDS`].
+    async fn detect_outages(&self) -> anyhow::Result<()> {
+        let now = Utc::now();
+        let window_start = now - Duration::hours(OUTAGE_SCAN_WINDOW_HOURS);
+
+        let server_ids: Vec<String> = sqlx::query(
+            r#"SELECT DISTINCT server_id FROM metrics_raw
+               UNION
+               SELECT DISTINCT server_id FROM outages WHERE "end" IS NULL"#,
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|row| row.get("server_id"))
+        .collect();
+
+        for server_id in server_ids {
+            let anchor: Option<DateTime<Utc>> = sqlx::query(
+                "SELECT timestamp FROM metrics_raw WHERE server_id = $1 AND timestamp < $2 ORDER BY timestamp DESC LIMIT 1",
+            )
+            .bind(&server_id)
+            .bind(window_start)
+            .fetch_optional(&self.pool)
+            .await?
+            .map(|row| row.get("timestamp"));
+
+            let samples: Vec<DateTime<Utc>> = sqlx::query(
+                "SELECT timestamp FROM metrics_raw WHERE server_id = $1 AND timestamp >= $2 ORDER BY timestamp ASC",
+            )
+            .bind(&server_id)
+            .bind(window_start)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|row| row.get("timestamp"))
+            .collect();
+
+            let mut open_outage: Option<(i64, DateTime<Utc>)> = sqlx::query(
+                r#"SELECT id, start FROM outages WHERE server_id = $1 AND "end" IS NULL ORDER BY start DESC LIMIT 1"#,
+            )
+            .bind(&server_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .map(|row| (row.get("id"), row.get("start")));
+
+            let mut prev: Option<DateTime<Utc>> = anchor;
+
+            for ts in samples {
+                let gap_down = prev
+                    .map(|p| (ts - p).num_seconds() > OUTAGE_SILENCE_THRESHOLD_SECONDS)
+                    .unwrap_or(false);
+                let row = sqlx::query(
+                    r#"SELECT
+                           COUNT(*) FILTER (WHERE reachable) AS reachable,
+                           COUNT(*) AS total
+                       FROM ping_targets_raw WHERE server_id = $1 AND timestamp = $2"#,
+                )
+                .bind(&server_id)
+                .bind(ts)
+                .fetch_one(&self.pool)
+                .await?;
+                let reachable: i64 = row.get("reachable");
+                let total: i64 = row.get("total");
+                let point_down = total > 0 && reachable == 0;
+                let is_down = point_down || gap_down;
+
+                match (is_down, &open_outage) {
+                    (true, None) => {
+                        // If the silence itself is what makes this sample "down", the
+                        // outage really began at `prev` (the last known-good sample),
+                        // not at `ts` (the first sample seen after recovering).
+                        let start = if gap_down { prev.unwrap_or(ts) } else { ts };
+                        let id: i64 = sqlx::query(
+                            "INSERT INTO outages (server_id, start) VALUES ($1, $2) RETURNING id",
+                        )
+                        .bind(&server_id)
+                        .bind(start)
+                        .fetch_one(&self.pool)
+                        .await?
+                        .get("id");
+                        open_outage = Some((id, start));
+                    }
+                    (false, Some((id, start))) => {
+                        let duration = (ts - *start).num_seconds().max(0);
+                        sqlx::query(r#"UPDATE outages SET "end" = $1, duration_seconds = $2 WHERE id = $3"#)
+                            .bind(ts)
+                            .bind(duration)
+                            .bind(*id)
+                            .execute(&self.pool)
+                            .await?;
+                        open_outage = None;
+                    }
+                    _ => {}
+                }
+
+                // `ts` itself answered after the silence, so the silence's
+                // outage ends at `ts` rather than at the next sample.
+                if gap_down && !point_down {
+                    if let Some((id, start)) = open_outage.take() {
+                        let duration = (ts - start).num_seconds().max(0);
+                        sqlx::query(r#"UPDATE outages SET "end" = $1, duration_seconds = $2 WHERE id = $3"#)
+                            .bind(ts)
+                            .bind(duration)
+                            .bind(id)
+                            .execute(&self.pool)
+                            .await?;
+                    }
+                }
+
+                prev = Some(ts);
+            }
+
+            // No sample since `prev` (the last real sample, or the
+            // pre-window anchor if this server reported nothing at all this
+            // window): treat the ongoing silence up to `now` the same as a
+            // point-down sample, opening an outage if one isn't already open.
+            let still_silent = prev
+                .map(|p| (now - p).num_seconds() > OUTAGE_SILENCE_THRESHOLD_SECONDS)
+                .unwrap_or(true);
+            if still_silent && open_outage.is_none() {
+                let start = prev.unwrap_or(now);
+                sqlx::query("INSERT INTO outages (server_id, start) VALUES ($1, $2)")
+                    .bind(&server_id)
+                    .bind(start)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn outage_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<Outage>> {
+        let rows = sqlx::query(
+            r#"SELECT server_id, start, "end", duration_seconds FROM outages
+               WHERE server_id = $1 AND start >= $2
+               ORDER BY start ASC"#,
+        )
+        .bind(server_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Outage {
+                server_id: row.get("server_id"),
+                start: row.get("start"),
+                end: row.get("end"),
+                duration_seconds: row.get("duration_seconds"),
+            })
+            .collect())
+    }
+
+    async fn sla_summary(&self, server_id: &str, days: i64) -> anyhow::Result<SlaSummary> {
+        let now = Utc::now();
+        let period_start = now - Duration::days(days);
+
+        let row = sqlx::query(
+            r#"SELECT
+                   COUNT(*) AS outage_count,
+                   COALESCE(SUM(
+                       extract(epoch FROM (LEAST(COALESCE("end", $3::timestamptz), $3::timestamptz)
+                                           - GREATEST(start, $2::timestamptz)))
+                   ), 0) AS total_outage_seconds
+               FROM outages
+               WHERE server_id = $1 AND start < $3::timestamptz AND ("end" IS NULL OR "end" > $2::timestamptz)"#,
+        )
+        .bind(server_id)
+        .bind(period_start)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let outage_count: i64 = row.get("outage_count");
+        let total_outage_seconds: f64 = row.get("total_outage_seconds");
+        let total_outage_seconds = total_outage_seconds.round() as i64;
+
+        let period_seconds = (now - period_start).num_seconds().max(1);
+        let availability_percent =
+            ((period_seconds - total_outage_seconds).max(0) as f64 / period_seconds as f64) * 100.0;
+
+        Ok(SlaSummary {
+            server_id: server_id.to_string(),
+            period_days: days,
+            availability_percent,
+            total_outage_seconds,
+            outage_count,
+        })
+    }
+
+    async fn hourly_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HourlyAggregate>> {
+        let rows = sqlx::query(
+            r#"SELECT server_id, hour_start, cpu_avg, cpu_max, cpu_p95, memory_avg, memory_max, disk_avg,
+                      net_rx_total, net_tx_total, ping_avg, ping_p50, ping_p95, ping_p99, sample_count
+               FROM metrics_hourly
+               WHERE server_id = $1 AND hour_start >= $2
+               ORDER BY hour_start ASC"#,
+        )
+        .bind(server_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| HourlyAggregate {
+                server_id: row.get("server_id"),
+                hour_start: row.get("hour_start"),
+                cpu_avg: row.get("cpu_avg"),
+                cpu_max: row.get("cpu_max"),
+                cpu_p95: row.get("cpu_p95"),
+                memory_avg: row.get("memory_avg"),
+                memory_max: row.get("memory_max"),
+                disk_avg: row.get("disk_avg"),
+                net_rx_total: row.get("net_rx_total"),
+                net_tx_total: row.get("net_tx_total"),
+                ping_avg: row.get("ping_avg"),
+                ping_p50: row.get("ping_p50"),
+                ping_p95: row.get("ping_p95"),
+                ping_p99: row.get("ping_p99"),
+                sample_count: row.get("sample_count"),
+            })
+            .collect())
+    }
+
+    async fn daily_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<DailyAggregate>> {
+        let rows = sqlx::query(
+            r#"SELECT server_id, date, cpu_avg, cpu_max, cpu_p95, memory_avg, memory_max, disk_avg,
+                      net_rx_total, net_tx_total, uptime_percent, ping_avg, ping_p50, ping_p95, ping_p99, sample_count
+               FROM metrics_daily
+               WHERE server_id = $1 AND date >= $2
+               ORDER BY date ASC"#,
+        )
+        .bind(server_id)
+        .bind(since.date_naive())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let date: chrono::NaiveDate = row.get("date");
+                DailyAggregate {
+                    server_id: row.get("server_id"),
+                    date: date.format("%Y-%m-%d").to_string(),
+                    cpu_avg: row.get("cpu_avg"),
+                    cpu_max: row.get("cpu_max"),
+                    cpu_p95: row.get("cpu_p95"),
+                    memory_avg: row.get("memory_avg"),
+                    memory_max: row.get("memory_max"),
+                    disk_avg: row.get("disk_avg"),
+                    net_rx_total: row.get("net_rx_total"),
+                    net_tx_total: row.get("net_tx_total"),
+                    uptime_percent: row.get("uptime_percent"),
+                    ping_avg: row.get("ping_avg"),
+                    ping_p50: row.get("ping_p50"),
+                    ping_p95: row.get("ping_p95"),
+                    ping_p99: row.get("ping_p99"),
+                    sample_count: row.get("sample_count"),
+                }
+            })
+            .collect())
+    }
+
+    async fn disk_hourly_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<DiskHourlyAggregate>> {
+        let rows = sqlx::query(
+            r#"SELECT server_id, mount_point, hour_start, usage_avg, available_min, sample_count
+               FROM metrics_disks_hourly
+               WHERE server_id = $1 AND hour_start >= $2
+               ORDER BY mount_point ASC, hour_start ASC"#,
+        )
+        .bind(server_id)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DiskHourlyAggregate {
+                server_id: row.get("server_id"),
+                mount_point: row.get("mount_point"),
+                hour_start: row.get("hour_start"),
+                usage_avg: row.get("usage_avg"),
+                available_min: row.get("available_min"),
+                sample_count: row.get("sample_count"),
+            })
+            .collect())
+    }
+
+    async fn disk_daily_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<DiskDailyAggregate>> {
+        let rows = sqlx::query(
+            r#"SELECT server_id, mount_point, date, usage_avg, available_min, sample_count
+               FROM metrics_disks_daily
+               WHERE server_id = $1 AND date >= $2
+               ORDER BY mount_point ASC, date ASC"#,
+        )
+        .bind(server_id)
+        .bind(since.date_naive())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let date: chrono::NaiveDate = row.get("date");
+                DiskDailyAggregate {
+                    server_id: row.get("server_id"),
+                    mount_point: row.get("mount_point"),
+                    date: date.format("%Y-%m-%d").to_string(),
+                    usage_avg: row.get("usage_avg"),
+                    available_min: row.get("available_min"),
+                    sample_count: row.get("sample_count"),
+                }
+            })
+            .collect())
+    }
+
+    async fn weekly_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<WeeklyAggregate>> {
+        let rows = sqlx::query(
+            r#"SELECT server_id, week_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg,
+                      net_rx_total, net_tx_total, uptime_percent, ping_avg, sample_count
+               FROM metrics_weekly
+               WHERE server_id = $1 AND week_start >= $2
+               ORDER BY week_start ASC"#,
+        )
+        .bind(server_id)
+        .bind(since.date_naive())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let week_start: chrono::NaiveDate = row.get("week_start");
+                WeeklyAggregate {
+                    server_id: row.get("server_id"),
+                    week_start: week_start.format("%Y-%m-%d").to_string(),
+                    cpu_avg: row.get("cpu_avg"),
+                    cpu_max: row.get("cpu_max"),
+                    memory_avg: row.get("memory_avg"),
+                    memory_max: row.get("memory_max"),
+                    disk_avg: row.get("disk_avg"),
+                    net_rx_total: row.get("net_rx_total"),
+                    net_tx_total: row.get("net_tx_total"),
+                    uptime_percent: row.get("uptime_percent"),
+                    ping_avg: row.get("ping_avg"),
+                    sample_count: row.get("sample_count"),
+                }
+            })
+            .collect())
+    }
+
+    async fn monthly_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<MonthlyAggregate>> {
+        let rows = sqlx::query(
+            r#"SELECT server_id, month_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg,
+                      net_rx_total, net_tx_total, uptime_percent, ping_avg, sample_count
+               FROM metrics_monthly
+               WHERE server_id = $1 AND month_start >= $2
+               ORDER BY month_start ASC"#,
+        )
+        .bind(server_id)
+        .bind(since.date_naive())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let month_start: chrono::NaiveDate = row.get("month_start");
+                MonthlyAggregate {
+                    server_id: row.get("server_id"),
+                    month_start: month_start.format("%Y-%m-%d").to_string(),
+                    cpu_avg: row.get("cpu_avg"),
+                    cpu_max: row.get("cpu_max"),
+                    memory_avg: row.get("memory_avg"),
+                    memory_max: row.get("memory_max"),
+                    disk_avg: row.get("disk_avg"),
+                    net_rx_total: row.get("net_rx_total"),
+                    net_tx_total: row.get("net_tx_total"),
+                    uptime_percent: row.get("uptime_percent"),
+                    ping_avg: row.get("ping_avg"),
+                    sample_count: row.get("sample_count"),
+                }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,1105 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::config::{get_db_path, RetentionPolicy};
+use crate::types::{
+    DailyAggregate, DiskDailyAggregate, DiskHourlyAggregate, HourlyAggregate, MonthlyAggregate,
+    Outage, SlaSummary, SystemMetrics, WeeklyAggregate,
+};
+
+use super::MetricsStore;
+
+/// How long a server can go without a new sample before it's considered
+/// down. Generous relative to the agent's ~10s reporting interval so a
+/// single dropped websocket frame doesn't open a spurious outage.
+const OUTAGE_SILENCE_THRESHOLD_SECONDS: i64 = 120;
+
+/// How far back `detect_outages` scans `metrics_raw`/`ping_targets_raw` on
+/// each run, in hours. Matches the `aggregate_hourly` cadence so an outage
+/// that opens and fully resolves between two runs is still observed in the
+/// window, instead of only the single most recent sample.
+const OUTAGE_SCAN_WINDOW_HOURS: i64 = 1;
+
+/// `MetricsStore` backed by the original local rusqlite database. Writes
+/// still serialize through one `Mutex`, matching the pre-trait behavior;
+/// `PostgresStore` is the escape hatch for multi-instance deployments that
+/// need to get off that lock.
+pub struct SqliteStore {
+    conn: Arc<Mutex<Connection>>,
+    retention: RetentionPolicy,
+}
+
+impl SqliteStore {
+    pub fn open(retention: RetentionPolicy) -> rusqlite::Result<Self> {
+        Ok(Self {
+            conn: Arc::new(Mutex::new(init_database()?)),
+            retention,
+        })
+    }
+
+    /// Run a blocking rusqlite closure on the tokio blocking pool so async
+    /// callers never stall waiting on the connection mutex.
+    async fn with_conn<T, F>(&self, f: F) -> anyhow::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        tokio::task::spawn_blocking(move || {
+            let guard = conn.lock().unwrap();
+            f(&guard)
+        })
+        .await?
+        .map_err(anyhow::Error::from)
+    }
+}
+
+#[async_trait]
+impl MetricsStore for SqliteStore {
+    async fn store_metrics(&self, server_id: &str, metrics: &SystemMetrics) -> anyhow::Result<()> {
+        let server_id = server_id.to_string();
+        let metrics = metrics.clone();
+        self.with_conn(move |conn| store_metrics(conn, &server_id, &metrics)).await
+    }
+
+    async fn aggregate_hourly(&self) -> anyhow::Result<()> {
+        self.with_conn(aggregate_hourly).await
+    }
+
+    async fn aggregate_daily(&self) -> anyhow::Result<()> {
+        self.with_conn(aggregate_daily).await
+    }
+
+    async fn aggregate_weekly(&self) -> anyhow::Result<()> {
+        if !self.retention.weekly_enabled {
+            return Ok(());
+        }
+        self.with_conn(aggregate_weekly).await
+    }
+
+    async fn aggregate_monthly(&self) -> anyhow::Result<()> {
+        if !self.retention.monthly_enabled {
+            return Ok(());
+        }
+        self.with_conn(aggregate_monthly).await
+    }
+
+    async fn cleanup_old_data(&self) -> anyhow::Result<()> {
+        let retention = self.retention.clone();
+        self.with_conn(move |conn| cleanup_old_data(conn, &retention)).await
+    }
+
+    async fn detect_outages(&self) -> anyhow::Result<()> {
+        self.with_conn(detect_outages).await
+    }
+
+    async fn outage_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<Outage>> {
+        let server_id = server_id.to_string();
+        self.with_conn(move |conn| outage_history(conn, &server_id, since)).await
+    }
+
+    async fn sla_summary(&self, server_id: &str, days: i64) -> anyhow::Result<SlaSummary> {
+        let server_id = server_id.to_string();
+        self.with_conn(move |conn| sla_summary(conn, &server_id, days)).await
+    }
+
+    async fn hourly_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<HourlyAggregate>> {
+        let server_id = server_id.to_string();
+        self.with_conn(move |conn| hourly_history(conn, &server_id, since)).await
+    }
+
+    async fn daily_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<DailyAggregate>> {
+        let server_id = server_id.to_string();
+        self.with_conn(move |conn| daily_history(conn, &server_id, since)).await
+    }
+
+    async fn disk_hourly_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<DiskHourlyAggregate>> {
+        let server_id = server_id.to_string();
+        self.with_conn(move |conn| disk_hourly_history(conn, &server_id, since)).await
+    }
+
+    async fn disk_daily_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<DiskDailyAggregate>> {
+        let server_id = server_id.to_string();
+        self.with_conn(move |conn| disk_daily_history(conn, &server_id, since)).await
+    }
+
+    async fn weekly_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<WeeklyAggregate>> {
+        let server_id = server_id.to_string();
+        self.with_conn(move |conn| weekly_history(conn, &server_id, since)).await
+    }
+
+    async fn monthly_history(
+        &self,
+        server_id: &str,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<MonthlyAggregate>> {
+        let server_id = server_id.to_string();
+        self.with_conn(move |conn| monthly_history(conn, &server_id, since)).await
+    }
+}
+
+fn init_database() -> rusqlite::Result<Connection> {
+    let conn = Connection::open(get_db_path())?;
+
+    conn.execute_batch(r#"
+        -- Raw metrics (kept for RetentionPolicy::raw_ttl_hours, 24 hours by default)
+        CREATE TABLE IF NOT EXISTS metrics_raw (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            timestamp TEXT NOT NULL,
+            cpu_usage REAL NOT NULL,
+            memory_usage REAL NOT NULL,
+            disk_usage REAL NOT NULL,
+            net_rx INTEGER NOT NULL,
+            net_tx INTEGER NOT NULL,
+            load_1 REAL NOT NULL,
+            load_5 REAL NOT NULL,
+            load_15 REAL NOT NULL,
+            ping_ms REAL,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+
+        -- Hourly aggregated metrics (kept for RetentionPolicy::hourly_ttl_days, 30 days by default)
+        CREATE TABLE IF NOT EXISTS metrics_hourly (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            hour_start TEXT NOT NULL,
+            cpu_avg REAL NOT NULL,
+            cpu_max REAL NOT NULL,
+            cpu_p95 REAL,
+            memory_avg REAL NOT NULL,
+            memory_max REAL NOT NULL,
+            disk_avg REAL NOT NULL,
+            net_rx_total INTEGER NOT NULL,
+            net_tx_total INTEGER NOT NULL,
+            ping_avg REAL,
+            ping_p50 REAL,
+            ping_p95 REAL,
+            ping_p99 REAL,
+            sample_count INTEGER NOT NULL,
+            UNIQUE(server_id, hour_start)
+        );
+
+        -- Daily aggregated metrics (kept for RetentionPolicy::daily_ttl_days, forever by default)
+        CREATE TABLE IF NOT EXISTS metrics_daily (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            date TEXT NOT NULL,
+            cpu_avg REAL NOT NULL,
+            cpu_max REAL NOT NULL,
+            cpu_p95 REAL,
+            memory_avg REAL NOT NULL,
+            memory_max REAL NOT NULL,
+            disk_avg REAL NOT NULL,
+            net_rx_total INTEGER NOT NULL,
+            net_tx_total INTEGER NOT NULL,
+            uptime_percent REAL NOT NULL,
+            ping_avg REAL,
+            ping_p50 REAL,
+            ping_p95 REAL,
+            ping_p99 REAL,
+            sample_count INTEGER NOT NULL,
+            UNIQUE(server_id, date)
+        );
+
+        -- Per-disk raw metrics (one row per mount point per sample)
+        CREATE TABLE IF NOT EXISTS metrics_disks_raw (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            timestamp TEXT NOT NULL,
+            mount_point TEXT NOT NULL,
+            disk_name TEXT NOT NULL,
+            usage_percent REAL NOT NULL,
+            total_bytes INTEGER NOT NULL,
+            available_bytes INTEGER NOT NULL,
+            UNIQUE(server_id, timestamp, mount_point)
+        );
+
+        -- Hourly per-mount disk rollups (same TTL as metrics_hourly)
+        CREATE TABLE IF NOT EXISTS metrics_disks_hourly (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            mount_point TEXT NOT NULL,
+            hour_start TEXT NOT NULL,
+            usage_avg REAL NOT NULL,
+            available_min INTEGER NOT NULL,
+            sample_count INTEGER NOT NULL,
+            UNIQUE(server_id, mount_point, hour_start)
+        );
+
+        -- Daily per-mount disk rollups (same TTL as metrics_daily)
+        CREATE TABLE IF NOT EXISTS metrics_disks_daily (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            mount_point TEXT NOT NULL,
+            date TEXT NOT NULL,
+            usage_avg REAL NOT NULL,
+            available_min INTEGER NOT NULL,
+            sample_count INTEGER NOT NULL,
+            UNIQUE(server_id, mount_point, date)
+        );
+
+        -- Per-target ping samples (keep alongside metrics_raw, same raw TTL)
+        CREATE TABLE IF NOT EXISTS ping_targets_raw (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            timestamp TEXT NOT NULL,
+            target_name TEXT NOT NULL,
+            target_host TEXT NOT NULL,
+            latency_ms REAL,
+            packet_loss REAL NOT NULL,
+            reachable INTEGER NOT NULL,
+            UNIQUE(server_id, timestamp, target_name)
+        );
+
+        -- Detected downtime intervals. `end`/`duration_seconds` are NULL
+        -- while the outage is still open.
+        CREATE TABLE IF NOT EXISTS outages (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            start TEXT NOT NULL,
+            end TEXT,
+            duration_seconds INTEGER
+        );
+
+        -- Weekly rollups, downsampled from metrics_daily (opt-in via RetentionPolicy::weekly_enabled)
+        CREATE TABLE IF NOT EXISTS metrics_weekly (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            week_start TEXT NOT NULL,
+            cpu_avg REAL NOT NULL,
+            cpu_max REAL NOT NULL,
+            memory_avg REAL NOT NULL,
+            memory_max REAL NOT NULL,
+            disk_avg REAL NOT NULL,
+            net_rx_total INTEGER NOT NULL,
+            net_tx_total INTEGER NOT NULL,
+            uptime_percent REAL NOT NULL,
+            ping_avg REAL,
+            sample_count INTEGER NOT NULL,
+            UNIQUE(server_id, week_start)
+        );
+
+        -- Monthly rollups, downsampled from metrics_daily (opt-in via RetentionPolicy::monthly_enabled)
+        CREATE TABLE IF NOT EXISTS metrics_monthly (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            server_id TEXT NOT NULL,
+            month_start TEXT NOT NULL,
+            cpu_avg REAL NOT NULL,
+            cpu_max REAL NOT NULL,
+            memory_avg REAL NOT NULL,
+            memory_max REAL NOT NULL,
+            disk_avg REAL NOT NULL,
+            net_rx_total INTEGER NOT NULL,
+            net_tx_total INTEGER NOT NULL,
+            uptime_percent REAL NOT NULL,
+            ping_avg REAL,
+            sample_count INTEGER NOT NULL,
+            UNIQUE(server_id, month_start)
+        );
+
+        -- Create indexes
+        CREATE INDEX IF NOT EXISTS idx_metrics_raw_server_time ON metrics_raw(server_id, timestamp);
+        CREATE INDEX IF NOT EXISTS idx_metrics_hourly_server_time ON metrics_hourly(server_id, hour_start);
+        CREATE INDEX IF NOT EXISTS idx_metrics_daily_server_time ON metrics_daily(server_id, date);
+        CREATE INDEX IF NOT EXISTS idx_metrics_disks_raw_server_time ON metrics_disks_raw(server_id, timestamp);
+        CREATE INDEX IF NOT EXISTS idx_metrics_disks_hourly_server_time ON metrics_disks_hourly(server_id, hour_start);
+        CREATE INDEX IF NOT EXISTS idx_metrics_disks_daily_server_time ON metrics_disks_daily(server_id, date);
+        CREATE INDEX IF NOT EXISTS idx_metrics_weekly_server_time ON metrics_weekly(server_id, week_start);
+        CREATE INDEX IF NOT EXISTS idx_metrics_monthly_server_time ON metrics_monthly(server_id, month_start);
+        CREATE INDEX IF NOT EXISTS idx_ping_targets_raw_server_time ON ping_targets_raw(server_id, timestamp);
+        CREATE INDEX IF NOT EXISTS idx_outages_server_start ON outages(server_id, start);
+        CREATE INDEX IF NOT EXISTS idx_outages_open ON outages(server_id, end);
+    "#)?;
+
+    // Add ping_ms column if it doesn't exist (migration for existing databases)
+    let _ = conn.execute("ALTER TABLE metrics_raw ADD COLUMN ping_ms REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_hourly ADD COLUMN ping_avg REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_daily ADD COLUMN ping_avg REAL", []);
+
+    // Add percentile columns if they don't exist (migration for existing databases)
+    let _ = conn.execute("ALTER TABLE metrics_hourly ADD COLUMN cpu_p95 REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_hourly ADD COLUMN ping_p50 REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_hourly ADD COLUMN ping_p95 REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_hourly ADD COLUMN ping_p99 REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_daily ADD COLUMN cpu_p95 REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_daily ADD COLUMN ping_p50 REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_daily ADD COLUMN ping_p95 REAL", []);
+    let _ = conn.execute("ALTER TABLE metrics_daily ADD COLUMN ping_p99 REAL", []);
+
+    Ok(conn)
+}
+
+fn store_metrics(conn: &Connection, server_id: &str, metrics: &SystemMetrics) -> rusqlite::Result<()> {
+    let disk_usage = metrics.disks.first().map(|d| d.usage_percent).unwrap_or(0.0);
+
+    // Get average ping latency from all targets
+    let ping_ms: Option<f64> = metrics.ping.as_ref().and_then(|p| {
+        let valid_pings: Vec<f64> = p.targets.iter()
+            .filter_map(|t| t.latency_ms)
+            .collect();
+        if valid_pings.is_empty() {
+            None
+        } else {
+            Some(valid_pings.iter().sum::<f64>() / valid_pings.len() as f64)
+        }
+    });
+
+    conn.execute(
+        r#"INSERT INTO metrics_raw (server_id, timestamp, cpu_usage, memory_usage, disk_usage, net_rx, net_tx, load_1, load_5, load_15, ping_ms)
+           VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)"#,
+        params![
+            server_id,
+            metrics.timestamp.to_rfc3339(),
+            metrics.cpu.usage,
+            metrics.memory.usage_percent,
+            disk_usage,
+            metrics.network.total_rx as i64,
+            metrics.network.total_tx as i64,
+            metrics.load_average.one,
+            metrics.load_average.five,
+            metrics.load_average.fifteen,
+            ping_ms,
+        ],
+    )?;
+
+    // Record every ping target individually so outage detection can see
+    // reachability per target instead of only the blended `ping_ms` above.
+    let timestamp = metrics.timestamp.to_rfc3339();
+    if let Some(ping) = &metrics.ping {
+        for target in &ping.targets {
+            // "degraded" (some but not all probe attempts succeeded) still
+            // got a response, so it counts as reachable for outage
+            // purposes; only "timeout"/"refused"/"error" mean the target
+            // didn't answer at all.
+            let reachable = target.status == "ok" || target.status == "degraded";
+            conn.execute(
+                r#"INSERT OR REPLACE INTO ping_targets_raw (server_id, timestamp, target_name, target_host, latency_ms, packet_loss, reachable)
+                   VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"#,
+                params![
+                    server_id,
+                    timestamp,
+                    target.name,
+                    target.host,
+                    target.latency_ms,
+                    target.packet_loss,
+                    reachable as i64,
+                ],
+            )?;
+        }
+    }
+
+    // Record every disk individually (keyed by mount point) so multi-mount
+    // servers and absolute free-space trends aren't collapsed into the
+    // single `disk_usage` figure above.
+    for disk in &metrics.disks {
+        let available_bytes = disk.total.saturating_sub(disk.used);
+        let mount_points: Vec<&str> = if disk.mount_points.is_empty() {
+            vec![disk.name.as_str()]
+        } else {
+            disk.mount_points.iter().map(|m| m.as_str()).collect()
+        };
+        for mount_point in mount_points {
+            conn.execute(
+                r#"INSERT OR REPLACE INTO metrics_disks_raw (server_id, timestamp, mount_point, disk_name, usage_percent, total_bytes, available_bytes)
+                   VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"#,
+                params![
+                    server_id,
+                    timestamp,
+                    mount_point,
+                    disk.name,
+                    disk.usage_percent,
+                    disk.total as i64,
+                    available_bytes as i64,
+                ],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn aggregate_hourly(conn: &Connection) -> rusqlite::Result<()> {
+    let hour_ago = Utc::now() - Duration::hours(1);
+    let hour_start = hour_ago.format("%Y-%m-%dT%H:00:00Z").to_string();
+
+    conn.execute(
+        r#"INSERT OR REPLACE INTO metrics_hourly (server_id, hour_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg, net_rx_total, net_tx_total, sample_count)
+           SELECT
+               server_id,
+               strftime('%Y-%m-%dT%H:00:00Z', timestamp) as hour,
+               AVG(cpu_usage),
+               MAX(cpu_usage),
+               AVG(memory_usage),
+               MAX(memory_usage),
+               AVG(disk_usage),
+               MAX(net_rx) - MIN(net_rx),
+               MAX(net_tx) - MIN(net_tx),
+               COUNT(*)
+           FROM metrics_raw
+           WHERE timestamp >= ?1 AND timestamp < datetime(?1, '+1 hour')
+           GROUP BY server_id, hour"#,
+        params![hour_start],
+    )?;
+
+    // SQLite has no percentile aggregate, so compute p50/p95/p99 in Rust:
+    // pull the raw values for each server in this window, sort them, and
+    // apply the nearest-rank method per percentile.
+    let server_ids: Vec<String> = {
+        let mut stmt = conn.prepare(
+            "SELECT DISTINCT server_id FROM metrics_raw WHERE timestamp >= ?1 AND timestamp < datetime(?1, '+1 hour')",
+        )?;
+        let rows = stmt.query_map(params![hour_start], |row| row.get::<_, String>(0))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    for server_id in server_ids {
+        let mut cpu_stmt = conn.prepare(
+            "SELECT cpu_usage FROM metrics_raw WHERE server_id = ?1 AND timestamp >= ?2 AND timestamp < datetime(?2, '+1 hour')",
+        )?;
+        let mut cpu_values: Vec<f64> = cpu_stmt
+            .query_map(params![server_id, hour_start], |row| row.get::<_, f64>(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        cpu_values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let cpu_p95 = nearest_rank_percentile(&cpu_values, 95.0);
+
+        let mut ping_stmt = conn.prepare(
+            "SELECT ping_ms FROM metrics_raw WHERE server_id = ?1 AND timestamp >= ?2 AND timestamp < datetime(?2, '+1 hour') AND ping_ms IS NOT NULL",
+        )?;
+        let mut ping_values: Vec<f64> = ping_stmt
+            .query_map(params![server_id, hour_start], |row| row.get::<_, f64>(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        ping_values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let ping_avg = if ping_values.is_empty() {
+            None
+        } else {
+            Some(ping_values.iter().sum::<f64>() / ping_values.len() as f64)
+        };
+        let ping_p50 = nearest_rank_percentile(&ping_values, 50.0);
+        let ping_p95 = nearest_rank_percentile(&ping_values, 95.0);
+        let ping_p99 = nearest_rank_percentile(&ping_values, 99.0);
+
+        conn.execute(
+            r#"UPDATE metrics_hourly
+               SET cpu_p95 = ?1, ping_avg = ?2, ping_p50 = ?3, ping_p95 = ?4, ping_p99 = ?5
+               WHERE server_id = ?6 AND hour_start = ?7"#,
+            params![cpu_p95, ping_avg, ping_p50, ping_p95, ping_p99, server_id, hour_start],
+        )?;
+    }
+
+    conn.execute(
+        r#"INSERT OR REPLACE INTO metrics_disks_hourly (server_id, mount_point, hour_start, usage_avg, available_min, sample_count)
+           SELECT
+               server_id,
+               mount_point,
+               strftime('%Y-%m-%dT%H:00:00Z', timestamp) as hour,
+               AVG(usage_percent),
+               MIN(available_bytes),
+               COUNT(*)
+           FROM metrics_disks_raw
+           WHERE timestamp >= ?1 AND timestamp < datetime(?1, '+1 hour')
+           GROUP BY server_id, mount_point, hour"#,
+        params![hour_start],
+    )?;
+
+    Ok(())
+}
+
+/// Nearest-rank percentile over already-sorted-ascending values: index is
+/// `ceil(p/100 * n) - 1`, clamped to `[0, n-1]`. `None` for an empty slice;
+/// a single value trivially satisfies every percentile.
+fn nearest_rank_percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    let n = sorted.len();
+    if n == 0 {
+        return None;
+    }
+    if n == 1 {
+        return Some(sorted[0]);
+    }
+    let rank = ((p / 100.0) * n as f64).ceil() as usize;
+    let index = rank.saturating_sub(1).min(n - 1);
+    Some(sorted[index])
+}
+
+fn aggregate_daily(conn: &Connection) -> rusqlite::Result<()> {
+    let yesterday = (Utc::now() - Duration::days(1)).format("%Y-%m-%d").to_string();
+
+    conn.execute(
+        r#"INSERT OR REPLACE INTO metrics_daily (server_id, date, cpu_avg, cpu_max, cpu_p95, memory_avg, memory_max, disk_avg, net_rx_total, net_tx_total, uptime_percent, ping_avg, ping_p50, ping_p95, ping_p99, sample_count)
+           SELECT
+               server_id,
+               date(hour_start) as day,
+               AVG(cpu_avg),
+               MAX(cpu_max),
+               AVG(cpu_p95),
+               AVG(memory_avg),
+               MAX(memory_max),
+               AVG(disk_avg),
+               SUM(net_rx_total),
+               SUM(net_tx_total),
+               (COUNT(*) * 100.0 / 24.0),
+               AVG(ping_avg),
+               AVG(ping_p50),
+               AVG(ping_p95),
+               AVG(ping_p99),
+               SUM(sample_count)
+           FROM metrics_hourly
+           WHERE date(hour_start) = ?1
+           GROUP BY server_id, day"#,
+        params![yesterday],
+    )?;
+
+    // Replace the crude sample_count/24 uptime estimate above with real
+    // availability derived from `outages` overlapping this calendar day.
+    let day_start = DateTime::parse_from_rfc3339(&format!("{yesterday}T00:00:00Z"))
+        .unwrap()
+        .with_timezone(&Utc);
+    let day_end = day_start + Duration::days(1);
+
+    let server_ids: Vec<String> = {
+        let mut stmt =
+            conn.prepare("SELECT DISTINCT server_id FROM metrics_daily WHERE date = ?1")?;
+        let rows = stmt.query_map(params![yesterday], |row| row.get::<_, String>(0))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    for server_id in server_ids {
+        let outage_seconds = outage_overlap_seconds(conn, &server_id, day_start, day_end)?;
+        let day_seconds = (day_end - day_start).num_seconds().max(1);
+        let uptime_percent =
+            ((day_seconds - outage_seconds).max(0) as f64 / day_seconds as f64) * 100.0;
+
+        conn.execute(
+            "UPDATE metrics_daily SET uptime_percent = ?1 WHERE server_id = ?2 AND date = ?3",
+            params![uptime_percent, server_id, yesterday],
+        )?;
+    }
+
+    conn.execute(
+        r#"INSERT OR REPLACE INTO metrics_disks_daily (server_id, mount_point, date, usage_avg, available_min, sample_count)
+           SELECT
+               server_id,
+               mount_point,
+               date(hour_start) as day,
+               AVG(usage_avg),
+               MIN(available_min),
+               SUM(sample_count)
+           FROM metrics_disks_hourly
+           WHERE date(hour_start) = ?1
+           GROUP BY server_id, mount_point, day"#,
+        params![yesterday],
+    )?;
+
+    Ok(())
+}
+
+/// Seconds of outage overlap with `[window_start, window_end)`, clamping
+/// each outage (open ones treated as ongoing through `window_end`) to the
+/// window before summing.
+fn outage_overlap_seconds(
+    conn: &Connection,
+    server_id: &str,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> rusqlite::Result<i64> {
+    let mut stmt = conn.prepare(
+        "SELECT start, end FROM outages WHERE server_id = ?1 AND start < ?2 AND (end IS NULL OR end > ?3)",
+    )?;
+    let rows = stmt.query_map(
+        params![server_id, window_end.to_rfc3339(), window_start.to_rfc3339()],
+        |row| {
+            let start: String = row.get(0)?;
+            let end: Option<String> = row.get(1)?;
+            Ok((start, end))
+        },
+    )?;
+
+    let mut total = 0i64;
+    for row in rows {
+        let (start, end) = row?;
+        let start_time = parse_rfc3339(start).max(window_start);
+        let end_time = end.map(parse_rfc3339).unwrap_or(window_end).min(window_end);
+        total += (end_time - start_time).num_seconds().max(0);
+    }
+    Ok(total)
+}
+
+/// Opens or closes `outages` rows for every server with recent activity (or
+/// an outage still open). Scans every sample in the trailing
+/// [`OUTAGE_SCAN_WINDOW_HOURS`] window (plus the one sample immediately
+/// before it, as a baseline for gap detection) rather than only the latest
+/// row, so an outage that both starts and resolves inside that window is
+/// still recorded instead of silently skipped. A moment is "down" when
+/// every ping target sampled at it was unreachable, or when the gap since
+/// the previous sample (or, at the tail of the window, since now) exceeds
+/// [`OUTAGE_SILENCE_THRESHOLD_SECONDS`].
+fn detect_outages(conn: &Connection) -> rusqlite::Result<()> {
+    let now = Utc::now();
+    let window_start = now - Duration::hours(OUTAGE_SCAN_WINDOW_HOURS);
+
+    let server_ids: Vec<String> = {
+        let mut stmt = conn.prepare(
+            "SELECT DISTINCT server_id FROM metrics_raw
+             UNION
+             SELECT DISTINCT server_id FROM outages WHERE end IS NULL",
+        )?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    for server_id in server_ids {
+        let anchor: Option<String> = conn
+            .query_row(
+                "SELECT timestamp FROM metrics_raw WHERE server_id = ?1 AND timestamp < ?2 ORDER BY timestamp DESC LIMIT 1",
+                params![server_id, window_start.to_rfc3339()],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        let samples: Vec<String> = {
+            let mut stmt = conn.prepare(
+                "SELECT timestamp FROM metrics_raw WHERE server_id = ?1 AND timestamp >= ?2 ORDER BY timestamp ASC",
+            )?;
+            let rows = stmt.query_map(params![server_id, window_start.to_rfc3339()], |row| row.get::<_, String>(0))?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+
+        let mut open_outage: Option<(i64, String)> = conn
+            .query_row(
+                "SELECT id, start FROM outages WHERE server_id = ?1 AND end IS NULL ORDER BY start DESC LIMIT 1",
+                params![server_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        let mut prev: Option<DateTime<Utc>> = anchor.map(parse_rfc3339);
+
+        for ts in &samples {
+            let cur = parse_rfc3339(ts.clone());
+
+            let gap_down = prev
+                .map(|p| (cur - p).num_seconds() > OUTAGE_SILENCE_THRESHOLD_SECONDS)
+                .unwrap_or(false);
+            let reachable: i64 = conn.query_row(
+                "SELECT COUNT(*) FROM ping_targets_raw WHERE server_id = ?1 AND timestamp = ?2 AND reachable = 1",
+                params![server_id, ts],
+                |row| row.get(0),
+            )?;
+            let total: i64 = conn.query_row(
+                "SELECT COUNT(*) FROM ping_targets_raw WHERE server_id = ?1 AND timestamp = ?2",
+                params![server_id, ts],
+                |row| row.get(0),
+            )?;
+            let point_down = total > 0 && reachable == 0;
+            let is_down = point_down || gap_down;
+
+            match (is_down, &open_outage) {
+                (true, None) => {
+                    // If the silence itself is what makes this sample "down", the
+                    // outage really began at `prev` (the last known-good sample),
+                    // not at `ts` (the first sample seen after recovering).
+                    let start = if gap_down {
+                        prev.map(|p| p.to_rfc3339()).unwrap_or_else(|| ts.clone())
+                    } else {
+                        ts.clone()
+                    };
+                    conn.execute(
+                        "INSERT INTO outages (server_id, start, end, duration_seconds) VALUES (?1, ?2, NULL, NULL)",
+                        params![server_id, start],
+                    )?;
+                    open_outage = Some((conn.last_insert_rowid(), start));
+                }
+                (false, Some((id, start))) => {
+                    let duration = (cur - parse_rfc3339(start.clone())).num_seconds().max(0);
+                    conn.execute(
+                        "UPDATE outages SET end = ?1, duration_seconds = ?2 WHERE id = ?3",
+                        params![ts, duration, id],
+                    )?;
+                    open_outage = None;
+                }
+                _ => {}
+            }
+
+            // `ts` itself answered after the silence, so the silence's
+            // outage ends at `ts` rather than at the next sample.
+            if gap_down && !point_down {
+                if let Some((id, start)) = open_outage.take() {
+                    let duration = (cur - parse_rfc3339(start)).num_seconds().max(0);
+                    conn.execute(
+                        "UPDATE outages SET end = ?1, duration_seconds = ?2 WHERE id = ?3",
+                        params![ts, duration, id],
+                    )?;
+                }
+            }
+
+            prev = Some(cur);
+        }
+
+        // No sample since `prev` (the last real sample, or the pre-window
+        // anchor if this server reported nothing at all this window):
+        // treat the ongoing silence up to `now` the same as a point-down
+        // sample, opening an outage if one isn't already open.
+        let still_silent = prev
+            .map(|p| (now - p).num_seconds() > OUTAGE_SILENCE_THRESHOLD_SECONDS)
+            .unwrap_or(true);
+        if still_silent && open_outage.is_none() {
+            let start = prev.map(|p| p.to_rfc3339()).unwrap_or_else(|| now.to_rfc3339());
+            conn.execute(
+                "INSERT INTO outages (server_id, start, end, duration_seconds) VALUES (?1, ?2, NULL, NULL)",
+                params![server_id, start],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn outage_history(
+    conn: &Connection,
+    server_id: &str,
+    since: DateTime<Utc>,
+) -> rusqlite::Result<Vec<Outage>> {
+    let mut stmt = conn.prepare(
+        "SELECT server_id, start, end, duration_seconds FROM outages WHERE server_id = ?1 AND start >= ?2 ORDER BY start ASC",
+    )?;
+    let rows = stmt.query_map(params![server_id, since.to_rfc3339()], |row| {
+        let end: Option<String> = row.get(2)?;
+        Ok(Outage {
+            server_id: row.get(0)?,
+            start: parse_rfc3339(row.get::<_, String>(1)?),
+            end: end.map(parse_rfc3339),
+            duration_seconds: row.get(3)?,
+        })
+    })?;
+    rows.collect()
+}
+
+fn sla_summary(conn: &Connection, server_id: &str, days: i64) -> rusqlite::Result<SlaSummary> {
+    let now = Utc::now();
+    let period_start = now - Duration::days(days);
+
+    let outage_count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM outages WHERE server_id = ?1 AND start < ?2 AND (end IS NULL OR end > ?3)",
+        params![server_id, now.to_rfc3339(), period_start.to_rfc3339()],
+        |row| row.get(0),
+    )?;
+    let total_outage_seconds = outage_overlap_seconds(conn, server_id, period_start, now)?;
+
+    let period_seconds = (now - period_start).num_seconds().max(1);
+    let availability_percent =
+        ((period_seconds - total_outage_seconds).max(0) as f64 / period_seconds as f64) * 100.0;
+
+    Ok(SlaSummary {
+        server_id: server_id.to_string(),
+        period_days: days,
+        availability_percent,
+        total_outage_seconds,
+        outage_count,
+    })
+}
+
+fn aggregate_weekly(conn: &Connection) -> rusqlite::Result<()> {
+    // ISO week start (Monday) of the week that just completed.
+    let week_start = (Utc::now() - Duration::weeks(1))
+        .date_naive()
+        .week(chrono::Weekday::Mon)
+        .first_day()
+        .format("%Y-%m-%d")
+        .to_string();
+
+    conn.execute(
+        r#"INSERT OR REPLACE INTO metrics_weekly (server_id, week_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg, net_rx_total, net_tx_total, uptime_percent, ping_avg, sample_count)
+           SELECT
+               server_id,
+               ?1,
+               AVG(cpu_avg),
+               MAX(cpu_max),
+               AVG(memory_avg),
+               MAX(memory_max),
+               AVG(disk_avg),
+               SUM(net_rx_total),
+               SUM(net_tx_total),
+               AVG(uptime_percent),
+               AVG(ping_avg),
+               SUM(sample_count)
+           FROM metrics_daily
+           WHERE date >= ?1 AND date < date(?1, '+7 days')
+           GROUP BY server_id"#,
+        params![week_start],
+    )?;
+
+    Ok(())
+}
+
+fn aggregate_monthly(conn: &Connection) -> rusqlite::Result<()> {
+    // First day of the calendar month before the current one.
+    let today = Utc::now().date_naive();
+    let month_start = (today - Duration::days(chrono::Datelike::day(&today) as i64))
+        .format("%Y-%m-01")
+        .to_string();
+
+    conn.execute(
+        r#"INSERT OR REPLACE INTO metrics_monthly (server_id, month_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg, net_rx_total, net_tx_total, uptime_percent, ping_avg, sample_count)
+           SELECT
+               server_id,
+               ?1,
+               AVG(cpu_avg),
+               MAX(cpu_max),
+               AVG(memory_avg),
+               MAX(memory_max),
+               AVG(disk_avg),
+               SUM(net_rx_total),
+               SUM(net_tx_total),
+               AVG(uptime_percent),
+               AVG(ping_avg),
+               SUM(sample_count)
+           FROM metrics_daily
+           WHERE date >= ?1 AND date < date(?1, '+1 month')
+           GROUP BY server_id"#,
+        params![month_start],
+    )?;
+
+    Ok(())
+}
+
+fn cleanup_old_data(conn: &Connection, retention: &RetentionPolicy) -> rusqlite::Result<()> {
+    // Delete raw data older than the configured raw TTL
+    let cutoff_raw = (Utc::now() - Duration::hours(retention.raw_ttl_hours)).to_rfc3339();
+    conn.execute("DELETE FROM metrics_raw WHERE timestamp < ?1", params![cutoff_raw])?;
+    conn.execute("DELETE FROM metrics_disks_raw WHERE timestamp < ?1", params![cutoff_raw])?;
+    conn.execute("DELETE FROM ping_targets_raw WHERE timestamp < ?1", params![cutoff_raw])?;
+
+    // Delete hourly data older than the configured hourly TTL
+    let cutoff_hourly = (Utc::now() - Duration::days(retention.hourly_ttl_days)).to_rfc3339();
+    conn.execute("DELETE FROM metrics_hourly WHERE hour_start < ?1", params![cutoff_hourly])?;
+    conn.execute("DELETE FROM metrics_disks_hourly WHERE hour_start < ?1", params![cutoff_hourly])?;
+
+    // Daily/weekly/monthly tiers keep their rows forever unless a TTL is set
+    if let Some(daily_ttl_days) = retention.daily_ttl_days {
+        let cutoff_daily = (Utc::now() - Duration::days(daily_ttl_days)).format("%Y-%m-%d").to_string();
+        conn.execute("DELETE FROM metrics_daily WHERE date < ?1", params![cutoff_daily])?;
+        conn.execute("DELETE FROM metrics_disks_daily WHERE date < ?1", params![cutoff_daily])?;
+    }
+    if let Some(weekly_ttl_days) = retention.weekly_ttl_days {
+        let cutoff_weekly = (Utc::now() - Duration::days(weekly_ttl_days)).format("%Y-%m-%d").to_string();
+        conn.execute("DELETE FROM metrics_weekly WHERE week_start < ?1", params![cutoff_weekly])?;
+    }
+    if let Some(monthly_ttl_days) = retention.monthly_ttl_days {
+        let cutoff_monthly = (Utc::now() - Duration::days(monthly_ttl_days)).format("%Y-%m-%d").to_string();
+        conn.execute("DELETE FROM metrics_monthly WHERE month_start < ?1", params![cutoff_monthly])?;
+    }
+
+    Ok(())
+}
+
+fn hourly_history(
+    conn: &Connection,
+    server_id: &str,
+    since: DateTime<Utc>,
+) -> rusqlite::Result<Vec<HourlyAggregate>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT server_id, hour_start, cpu_avg, cpu_max, cpu_p95, memory_avg, memory_max, disk_avg,
+                  net_rx_total, net_tx_total, ping_avg, ping_p50, ping_p95, ping_p99, sample_count
+           FROM metrics_hourly
+           WHERE server_id = ?1 AND hour_start >= ?2
+           ORDER BY hour_start ASC"#,
+    )?;
+    let rows = stmt.query_map(params![server_id, since.to_rfc3339()], |row| {
+        Ok(HourlyAggregate {
+            server_id: row.get(0)?,
+            hour_start: parse_rfc3339(row.get::<_, String>(1)?),
+            cpu_avg: row.get(2)?,
+            cpu_max: row.get(3)?,
+            cpu_p95: row.get(4)?,
+            memory_avg: row.get(5)?,
+            memory_max: row.get(6)?,
+            disk_avg: row.get(7)?,
+            net_rx_total: row.get(8)?,
+            net_tx_total: row.get(9)?,
+            ping_avg: row.get(10)?,
+            ping_p50: row.get(11)?,
+            ping_p95: row.get(12)?,
+            ping_p99: row.get(13)?,
+            sample_count: row.get(14)?,
+        })
+    })?;
+    rows.collect()
+}
+
+fn daily_history(
+    conn: &Connection,
+    server_id: &str,
+    since: DateTime<Utc>,
+) -> rusqlite::Result<Vec<DailyAggregate>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT server_id, date, cpu_avg, cpu_max, cpu_p95, memory_avg, memory_max, disk_avg,
+                  net_rx_total, net_tx_total, uptime_percent, ping_avg, ping_p50, ping_p95, ping_p99, sample_count
+           FROM metrics_daily
+           WHERE server_id = ?1 AND date >= ?2
+           ORDER BY date ASC"#,
+    )?;
+    let rows = stmt.query_map(params![server_id, since.format("%Y-%m-%d").to_string()], |row| {
+        Ok(DailyAggregate {
+            server_id: row.get(0)?,
+            date: row.get(1)?,
+            cpu_avg: row.get(2)?,
+            cpu_max: row.get(3)?,
+            cpu_p95: row.get(4)?,
+            memory_avg: row.get(5)?,
+            memory_max: row.get(6)?,
+            disk_avg: row.get(7)?,
+            net_rx_total: row.get(8)?,
+            net_tx_total: row.get(9)?,
+            uptime_percent: row.get(10)?,
+            ping_avg: row.get(11)?,
+            ping_p50: row.get(12)?,
+            ping_p95: row.get(13)?,
+            ping_p99: row.get(14)?,
+            sample_count: row.get(15)?,
+        })
+    })?;
+    rows.collect()
+}
+
+fn disk_hourly_history(
+    conn: &Connection,
+    server_id: &str,
+    since: DateTime<Utc>,
+) -> rusqlite::Result<Vec<DiskHourlyAggregate>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT server_id, mount_point, hour_start, usage_avg, available_min, sample_count
+           FROM metrics_disks_hourly
+           WHERE server_id = ?1 AND hour_start >= ?2
+           ORDER BY mount_point ASC, hour_start ASC"#,
+    )?;
+    let rows = stmt.query_map(params![server_id, since.to_rfc3339()], |row| {
+        Ok(DiskHourlyAggregate {
+            server_id: row.get(0)?,
+            mount_point: row.get(1)?,
+            hour_start: parse_rfc3339(row.get::<_, String>(2)?),
+            usage_avg: row.get(3)?,
+            available_min: row.get(4)?,
+            sample_count: row.get(5)?,
+        })
+    })?;
+    rows.collect()
+}
+
+fn disk_daily_history(
+    conn: &Connection,
+    server_id: &str,
+    since: DateTime<Utc>,
+) -> rusqlite::Result<Vec<DiskDailyAggregate>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT server_id, mount_point, date, usage_avg, available_min, sample_count
+           FROM metrics_disks_daily
+           WHERE server_id = ?1 AND date >= ?2
+           ORDER BY mount_point ASC, date ASC"#,
+    )?;
+    let rows = stmt.query_map(params![server_id, since.format("%Y-%m-%d").to_string()], |row| {
+        Ok(DiskDailyAggregate {
+            server_id: row.get(0)?,
+            mount_point: row.get(1)?,
+            date: row.get(2)?,
+            usage_avg: row.get(3)?,
+            available_min: row.get(4)?,
+            sample_count: row.get(5)?,
+        })
+    })?;
+    rows.collect()
+}
+
+fn weekly_history(
+    conn: &Connection,
+    server_id: &str,
+    since: DateTime<Utc>,
+) -> rusqlite::Result<Vec<WeeklyAggregate>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT server_id, week_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg,
+                  net_rx_total, net_tx_total, uptime_percent, ping_avg, sample_count
+           FROM metrics_weekly
+           WHERE server_id = ?1 AND week_start >= ?2
+           ORDER BY week_start ASC"#,
+    )?;
+    let rows = stmt.query_map(params![server_id, since.format("%Y-%m-%d").to_string()], |row| {
+        Ok(WeeklyAggregate {
+            server_id: row.get(0)?,
+            week_start: row.get(1)?,
+            cpu_avg: row.get(2)?,
+            cpu_max: row.get(3)?,
+            memory_avg: row.get(4)?,
+            memory_max: row.get(5)?,
+            disk_avg: row.get(6)?,
+            net_rx_total: row.get(7)?,
+            net_tx_total: row.get(8)?,
+            uptime_percent: row.get(9)?,
+            ping_avg: row.get(10)?,
+            sample_count: row.get(11)?,
+        })
+    })?;
+    rows.collect()
+}
+
+fn monthly_history(
+    conn: &Connection,
+    server_id: &str,
+    since: DateTime<Utc>,
+) -> rusqlite::Result<Vec<MonthlyAggregate>> {
+    let mut stmt = conn.prepare(
+        r#"SELECT server_id, month_start, cpu_avg, cpu_max, memory_avg, memory_max, disk_avg,
+                  net_rx_total, net_tx_total, uptime_percent, ping_avg, sample_count
+           FROM metrics_monthly
+           WHERE server_id = ?1 AND month_start >= ?2
+           ORDER BY month_start ASC"#,
+    )?;
+    let rows = stmt.query_map(params![server_id, since.format("%Y-%m-%d").to_string()], |row| {
+        Ok(MonthlyAggregate {
+            server_id: row.get(0)?,
+            month_start: row.get(1)?,
+            cpu_avg: row.get(2)?,
+            cpu_max: row.get(3)?,
+            memory_avg: row.get(4)?,
+            memory_max: row.get(5)?,
+            disk_avg: row.get(6)?,
+            net_rx_total: row.get(7)?,
+            net_tx_total: row.get(8)?,
+            uptime_percent: row.get(9)?,
+            ping_avg: row.get(10)?,
+            sample_count: row.get(11)?,
+        })
+    })?;
+    rows.collect()
+}
+
+fn parse_rfc3339(s: String) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(&s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
@@ -0,0 +1,369 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// JWT claims embedded in the admin auth token, validated by
+/// [`crate::middleware::auth_middleware`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub exp: usize,
+}
+
+/// Latest metrics snapshot received from a connected agent, keyed by
+/// `server_id` in `AppState.agent_metrics`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentMetricsData {
+    pub server_id: String,
+    pub metrics: SystemMetrics,
+    pub received_at: DateTime<Utc>,
+}
+
+/// Mirrors the wire format an agent sends over its websocket connection.
+/// Kept in sync with `vstats-agent`'s own `types::SystemMetrics`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemMetrics {
+    pub timestamp: DateTime<Utc>,
+    pub hostname: String,
+    pub os: OsInfo,
+    pub cpu: CpuMetrics,
+    pub memory: MemoryMetrics,
+    pub disks: Vec<DiskMetrics>,
+    pub network: NetworkMetrics,
+    pub uptime: u64,
+    pub load_average: LoadAverage,
+    pub ping: Option<PingMetrics>,
+    pub dns: Option<DnsMetrics>,
+    pub version: Option<String>,
+    pub ip_addresses: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OsInfo {
+    pub name: String,
+    pub version: String,
+    pub kernel: String,
+    pub arch: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CpuMetrics {
+    pub brand: String,
+    pub cores: usize,
+    pub usage: f32,
+    pub frequency: u64,
+    pub per_core: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemoryMetrics {
+    pub total: u64,
+    pub used: u64,
+    pub available: u64,
+    pub swap_total: u64,
+    pub swap_used: u64,
+    pub usage_percent: f32,
+    pub modules: Vec<MemoryModule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryModule {
+    pub slot: Option<String>,
+    pub size: u64,
+    pub mem_type: Option<String>,
+    pub speed: Option<u32>,
+    pub manufacturer: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskMetrics {
+    pub name: String,
+    pub model: Option<String>,
+    pub serial: Option<String>,
+    pub total: u64,
+    pub disk_type: Option<String>,
+    pub mount_points: Vec<String>,
+    pub usage_percent: f32,
+    pub used: u64,
+    pub read_bytes_per_sec: u64,
+    pub write_bytes_per_sec: u64,
+    pub read_iops: u64,
+    pub write_iops: u64,
+    pub read_latency_ms: Option<f64>,
+    pub write_latency_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkInterface {
+    pub name: String,
+    pub mac: Option<String>,
+    pub speed: Option<u32>,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_speed: u64,
+    pub tx_speed: u64,
+    pub rx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_errors: u64,
+    pub tx_dropped: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkMetrics {
+    pub interfaces: Vec<NetworkInterface>,
+    pub total_rx: u64,
+    pub total_tx: u64,
+    pub rx_speed: u64,
+    pub tx_speed: u64,
+    pub errors: Option<NetworkErrorStats>,
+    pub limits: Option<NetworkLimits>,
+}
+
+/// Kernel network-buffer tunables, sampled infrequently since they rarely
+/// change. Mirrors the agent's own `types::NetworkLimits`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkLimits {
+    pub rmem_max: Option<u64>,
+    pub wmem_max: Option<u64>,
+    pub rmem_default: Option<u64>,
+    pub wmem_default: Option<u64>,
+    pub netdev_max_backlog: Option<u64>,
+    pub tcp_rmem: Option<TcpMemLimits>,
+    pub tcp_wmem: Option<TcpMemLimits>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TcpMemLimits {
+    pub min: u64,
+    pub default: u64,
+    pub max: u64,
+}
+
+/// Per-second network error/protocol counters, mirrored from the agent's
+/// `/proc/net/dev` and `/proc/net/snmp` parsing. `None` on non-Linux hosts,
+/// or when the proc files can't be read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkErrorStats {
+    pub rx_errors_per_sec: f64,
+    pub rx_dropped_per_sec: f64,
+    pub rx_fifo_errors_per_sec: f64,
+    pub tx_errors_per_sec: f64,
+    pub tx_dropped_per_sec: f64,
+    pub tx_fifo_errors_per_sec: f64,
+    pub tx_collisions_per_sec: f64,
+    pub udp_in_errors_per_sec: f64,
+    pub udp_rcvbuf_errors_per_sec: f64,
+    pub udp_sndbuf_errors_per_sec: f64,
+    pub udp_no_ports_per_sec: f64,
+    pub udp_in_csum_errors_per_sec: f64,
+    pub tcp_retrans_segs_per_sec: f64,
+    pub udp_in_datagrams_per_sec: f64,
+    pub udp_out_datagrams_per_sec: f64,
+    pub tcp_in_errors_per_sec: f64,
+    pub totals: ProtocolTotals,
+}
+
+/// Cumulative (never-reset) counterparts of the `NetworkErrorStats` rates.
+/// Mirrors the agent's own `types::ProtocolTotals`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProtocolTotals {
+    pub rx_errors: u64,
+    pub rx_dropped: u64,
+    pub rx_fifo_errors: u64,
+    pub tx_errors: u64,
+    pub tx_dropped: u64,
+    pub tx_fifo_errors: u64,
+    pub tx_collisions: u64,
+    pub udp_in_datagrams: u64,
+    pub udp_out_datagrams: u64,
+    pub udp_in_errors: u64,
+    pub udp_rcvbuf_errors: u64,
+    pub udp_sndbuf_errors: u64,
+    pub udp_no_ports: u64,
+    pub udp_in_csum_errors: u64,
+    pub tcp_retrans_segs: u64,
+    pub tcp_in_errors: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingMetrics {
+    pub targets: Vec<PingTarget>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingTarget {
+    pub name: String,
+    pub host: String,
+    pub latency_ms: Option<f64>,
+    pub packet_loss: f64,
+    pub status: String,
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub probe: ProbeKind,
+}
+
+/// Resolution-latency metric complementing the ICMP/TCP ping targets.
+/// Mirrors the agent's own `types::DnsMetrics`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsMetrics {
+    pub resolvers: Vec<DnsResolverResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsResolverResult {
+    pub address: String,
+    pub resolve_ms: Option<f64>,
+    pub status: String,
+}
+
+/// Which transport a ping target is checked with: an ICMP `ping` or an
+/// in-process TCP connect-timeout probe against `host:port`. Mirrors the
+/// agent's own `types::ProbeKind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProbeKind {
+    Icmp,
+    Tcp,
+}
+
+impl Default for ProbeKind {
+    fn default() -> Self {
+        ProbeKind::Icmp
+    }
+}
+
+/// One row of `metrics_hourly`, returned by `MetricsStore::hourly_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HourlyAggregate {
+    pub server_id: String,
+    pub hour_start: DateTime<Utc>,
+    pub cpu_avg: f64,
+    pub cpu_max: f64,
+    pub cpu_p95: Option<f64>,
+    pub memory_avg: f64,
+    pub memory_max: f64,
+    pub disk_avg: f64,
+    pub net_rx_total: i64,
+    pub net_tx_total: i64,
+    pub ping_avg: Option<f64>,
+    pub ping_p50: Option<f64>,
+    pub ping_p95: Option<f64>,
+    pub ping_p99: Option<f64>,
+    pub sample_count: i64,
+}
+
+/// One row of `metrics_daily`, returned by `MetricsStore::daily_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyAggregate {
+    pub server_id: String,
+    pub date: String,
+    pub cpu_avg: f64,
+    pub cpu_max: f64,
+    pub cpu_p95: Option<f64>,
+    pub memory_avg: f64,
+    pub memory_max: f64,
+    pub disk_avg: f64,
+    pub net_rx_total: i64,
+    pub net_tx_total: i64,
+    pub uptime_percent: f64,
+    pub ping_avg: Option<f64>,
+    pub ping_p50: Option<f64>,
+    pub ping_p95: Option<f64>,
+    pub ping_p99: Option<f64>,
+    pub sample_count: i64,
+}
+
+/// One row of `metrics_disks_hourly`: per-mount utilization and free-space
+/// trend, returned by `MetricsStore::disk_hourly_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskHourlyAggregate {
+    pub server_id: String,
+    pub mount_point: String,
+    pub hour_start: DateTime<Utc>,
+    pub usage_avg: f64,
+    pub available_min: i64,
+    pub sample_count: i64,
+}
+
+/// One row of `metrics_disks_daily`, returned by
+/// `MetricsStore::disk_daily_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskDailyAggregate {
+    pub server_id: String,
+    pub mount_point: String,
+    pub date: String,
+    pub usage_avg: f64,
+    pub available_min: i64,
+    pub sample_count: i64,
+}
+
+/// One row of `outages`: a detected downtime interval for a server, derived
+/// from gaps in ingestion or samples where every ping target was
+/// unreachable. `end`/`duration_seconds` are `None` while the outage is
+/// still open.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Outage {
+    pub server_id: String,
+    pub start: DateTime<Utc>,
+    pub end: Option<DateTime<Utc>>,
+    pub duration_seconds: Option<i64>,
+}
+
+/// Rolling-window availability for a server, computed directly from
+/// `outages` rather than the coarser `metrics_daily.uptime_percent` column.
+/// Returned by `MetricsStore::sla_summary` for the 30/90-day SLA figures.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaSummary {
+    pub server_id: String,
+    pub period_days: i64,
+    pub availability_percent: f64,
+    pub total_outage_seconds: i64,
+    pub outage_count: i64,
+}
+
+/// One row of `metrics_weekly`, downsampled from `metrics_daily` when
+/// `RetentionPolicy::weekly_enabled` is set. Returned by
+/// `MetricsStore::weekly_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyAggregate {
+    pub server_id: String,
+    pub week_start: String,
+    pub cpu_avg: f64,
+    pub cpu_max: f64,
+    pub memory_avg: f64,
+    pub memory_max: f64,
+    pub disk_avg: f64,
+    pub net_rx_total: i64,
+    pub net_tx_total: i64,
+    pub uptime_percent: f64,
+    pub ping_avg: Option<f64>,
+    pub sample_count: i64,
+}
+
+/// One row of `metrics_monthly`, downsampled from `metrics_daily` when
+/// `RetentionPolicy::monthly_enabled` is set. Returned by
+/// `MetricsStore::monthly_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonthlyAggregate {
+    pub server_id: String,
+    pub month_start: String,
+    pub cpu_avg: f64,
+    pub cpu_max: f64,
+    pub memory_avg: f64,
+    pub memory_max: f64,
+    pub disk_avg: f64,
+    pub net_rx_total: i64,
+    pub net_tx_total: i64,
+    pub uptime_percent: f64,
+    pub ping_avg: Option<f64>,
+    pub sample_count: i64,
+}